@@ -0,0 +1,103 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two
+}
+
+impl Part {
+    pub fn parse(string: &str) -> Option<Part> {
+        match string {
+            "1" => Some(Part::One),
+            "2" => Some(Part::Two),
+            _ => None
+        }
+    }
+
+    // Is this part wanted when running the given selection (None is all parts)
+    pub fn wanted(self, selected: Option<Part>) -> bool {
+        match selected {
+            Some(part) => part == self,
+            None => true
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => f.write_str("1"),
+            Part::Two => f.write_str("2")
+        }
+    }
+}
+
+// Print an answer. Multi-line answers start on their own line
+pub fn print_answer<A: fmt::Display>(part: Part, answer: A) {
+    let answer = answer.to_string();
+
+    if answer.contains('\n') {
+        println!("Part {}:", part);
+        println!("{}", answer.trim_end());
+    } else {
+        println!("Part {}: {}", part, answer);
+    }
+}
+
+// Parse recorded answers in the format written by print_answer
+pub fn parse_answers(text: &str) -> Result<Vec<(Part, String)>, String> {
+    let mut answers: Vec<(Part, String)> = Vec::new();
+
+    for (num, line) in text.lines().enumerate() {
+        if let Some(rest) = line.strip_prefix("Part ") {
+            let (part, answer) = rest.split_once(':').ok_or_else(|| format!("line {}: expected ':' after part", num + 1))?;
+            let part = Part::parse(part).ok_or_else(|| format!("line {}: invalid part {}", num + 1, part))?;
+
+            answers.push((part, answer.trim().to_string()));
+        } else if let Some((_, answer)) = answers.last_mut() {
+            // Continuation of a multi-line answer
+            if !answer.is_empty() {
+                answer.push('\n');
+            }
+
+            answer.push_str(line);
+        } else if !line.trim().is_empty() {
+            Err(format!("line {}: expected 'Part N:'", num + 1))?
+        }
+    }
+
+    for (_, answer) in answers.iter_mut() {
+        *answer = answer.trim_end().to_string();
+    }
+
+    Ok(answers)
+}
+
+// Compare answers ignoring trailing whitespace on each line
+pub fn same_answer(expected: &str, actual: &str) -> bool {
+    expected.trim_end().lines().map(str::trim_end).eq(actual.trim_end().lines().map(str::trim_end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = parse_answers("Part 1: 301\nPart 2:\n# #\n # \n").unwrap();
+
+        assert!(answers == vec![(Part::One, "301".to_string()), (Part::Two, "# #\n #".to_string())]);
+
+        assert!(parse_answers("Part 3: 1").is_err());
+        assert!(parse_answers("301").is_err());
+    }
+
+    #[test]
+    fn test_same_answer() {
+        assert!(same_answer("# #", "# #  \n"));
+        assert!(same_answer("# #\n #", "# #  \n # \n"));
+        assert!(!same_answer("301", "302"));
+        assert!(!same_answer("# #", "# #\n #"));
+    }
+}
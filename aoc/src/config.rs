@@ -0,0 +1,124 @@
+use crate::{input::{Input, Source}, parse::LineError, solution::Result};
+use std::{collections::BTreeMap, path::Path, str::FromStr, sync::Mutex};
+
+// Settings which replace puzzle constants, keyed by day and name (e.g. day13.dest_x).
+// Only the run commands set them, so verification always uses the built in values
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    values: BTreeMap<String, String>
+}
+
+impl Config {
+    // Command line options handled by parse_arg
+    pub const USAGE: &'static str = "[--config PATH] [--set KEY=VALUE]";
+
+    // Handle a command line option, calling value for its argument. Returns false if it isn't a config option
+    pub fn parse_arg<F, S>(&mut self, arg: &str, value: F) -> Result<bool>
+    where F: FnOnce() -> std::result::Result<S, String>, S: AsRef<str> {
+        match arg {
+            "--config" => self.load(value()?.as_ref())?,
+            "--set" => {
+                let value = value()?;
+                let (key, value) = split_setting(value.as_ref()).ok_or_else(|| format!("Invalid setting {} (expecting KEY=VALUE)", value.as_ref()))?;
+
+                self.set(key, value);
+            }
+            _ => return Ok(false)
+        }
+
+        Ok(true)
+    }
+
+    pub fn set(&mut self, key: &str, value: &str) {
+        self.values.insert(key.to_string(), value.to_string());
+    }
+
+    // Read KEY = VALUE lines. Keys after a [dayNN] line are in that day. # starts a comment
+    pub fn load<P: AsRef<Path>>(&mut self, path: P) -> Result<()> {
+        let input = Input::from_file(path)?;
+        let mut section = String::new();
+
+        let settings = input.parse_lines(|l| {
+            let line = l.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                return Ok(None)
+            }
+
+            if let Some(name) = line.strip_prefix('[') {
+                section = name.strip_suffix(']').ok_or_else(|| LineError::at_end(l, "expected ] after section name"))?.trim().to_string();
+                return Ok(None)
+            }
+
+            match split_setting(line) {
+                Some((key, value)) if section.is_empty() => Ok(Some((key.to_string(), value.to_string()))),
+                Some((key, value)) => Ok(Some((format!("{}.{}", section, key), value.to_string()))),
+                None => Err(LineError::new("expected KEY = VALUE"))
+            }
+        })?;
+
+        self.values.extend(settings.into_iter().flatten());
+
+        Ok(())
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(|v| v.as_str())
+    }
+}
+
+fn split_setting(setting: &str) -> Option<(&str, &str)> {
+    let (key, value) = setting.split_once('=')?;
+    let key = key.trim();
+
+    if key.is_empty() {
+        None
+    } else {
+        Some((key, value.trim()))
+    }
+}
+
+static CONFIG: Mutex<Config> = Mutex::new(Config { values: BTreeMap::new() });
+
+pub fn set_config(config: Config) {
+    *CONFIG.lock().unwrap() = config;
+}
+
+// A setting, or the default if it isn't set
+pub fn get<T: FromStr>(key: &str, default: T) -> Result<T> {
+    match CONFIG.lock().unwrap().get(key) {
+        Some(value) => Ok(value.parse().map_err(|_| format!("Invalid value {} for {}", value, key))?),
+        None => Ok(default)
+    }
+}
+
+// Puzzle input given as a setting, for days whose input is a single value
+pub fn input(key: &str, default: &'static str) -> Source {
+    match CONFIG.lock().unwrap().get(key) {
+        Some(value) => Source::Setting { key: key.to_string(), value: value.to_string() },
+        None => Source::Builtin(default)
+    }
+}
+
+#[test]
+fn test_config() {
+    let mut config = Config::default();
+    let path = std::env::temp_dir().join(format!("aoc-config-{}.txt", std::process::id()));
+
+    std::fs::write(&path, "# Team inputs\nday05.input = abc\n\n[day13]\ndest_x = 7\n dest_y=4 \n").unwrap();
+
+    assert!(config.parse_arg("--config", || Ok(path.to_str().unwrap())).unwrap());
+    assert!(config.parse_arg("--set", || Ok("day13.dest_x=8")).unwrap());
+    assert!(config.parse_arg("--set", || Ok("=8")).is_err());
+    assert!(!config.parse_arg("--part", || Ok("1")).unwrap());
+
+    assert!(config.get("day05.input") == Some("abc"));
+    assert!(config.get("day13.dest_x") == Some("8"));
+    assert!(config.get("day13.dest_y") == Some("4"));
+    assert!(config.get("dest_y").is_none());
+
+    std::fs::write(&path, "[day13\n").unwrap();
+    assert!(config.load(&path).unwrap_err().to_string().ends_with("line 1, column 7: expected ] after section name"));
+
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,304 @@
+use crate::parse::LineError;
+use memmap2::Mmap;
+use std::{env, error, fmt, fs::File, io::{self, Read}, path::{Path, PathBuf}};
+
+// Where to read puzzle input from
+#[derive(Debug, Clone)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+    Builtin(&'static str),
+    // Given in the config
+    Setting { key: String, value: String }
+}
+
+impl Source {
+    // Take the source from the first command line argument ("-" for stdin), falling back to a default
+    pub fn from_args(default: Source) -> Source {
+        match env::args().nth(1) {
+            Some(arg) => Source::from_arg(&arg),
+            None => default
+        }
+    }
+
+    pub fn file(path: &str) -> Source {
+        Source::File(PathBuf::from(path))
+    }
+
+    pub fn from_arg(arg: &str) -> Source {
+        if arg == "-" {
+            Source::Stdin
+        } else {
+            Source::File(PathBuf::from(arg))
+        }
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            Source::Stdin => "<stdin>".to_string(),
+            Source::File(path) => path.display().to_string(),
+            Source::Builtin(_) => "<built-in>".to_string(),
+            Source::Setting { key, .. } => key.clone()
+        }
+    }
+}
+
+pub enum InputError {
+    Io { file: String, error: io::Error },
+    Empty { file: String },
+    Parse { file: String, line: usize, error: LineError }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let file = match self {
+            InputError::Io { file, .. } | InputError::Empty { file } | InputError::Parse { file, .. } => file
+        };
+
+        // Unnamed input leaves the file name to the caller
+        if !file.is_empty() {
+            write!(f, "{}: ", file)?;
+        }
+
+        match self {
+            InputError::Io { error, .. } => write!(f, "{}", error),
+            InputError::Empty { .. } => write!(f, "no input found"),
+            InputError::Parse { line, error, .. } => {
+                write!(f, "line {}", line)?;
+
+                if let Some(column) = error.column {
+                    write!(f, ", column {}", column)?;
+                }
+
+                write!(f, ": {}", error.message)?;
+
+                if let Some(token) = &error.token {
+                    write!(f, " '{}'", token)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+// Debug is what main prints on error, so keep it readable
+impl fmt::Debug for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl error::Error for InputError {}
+
+pub type Result<T> = std::result::Result<T, InputError>;
+
+// Puzzle input text along with the name of where it came from
+pub struct Input {
+    name: String,
+    text: String
+}
+
+// Unnamed input, as passed to a solution
+impl From<&str> for Input {
+    fn from(text: &str) -> Input {
+        Input::from_text("", text)
+    }
+}
+
+impl Input {
+    pub fn from_args(default: Source) -> Result<Input> {
+        Input::load(&Source::from_args(default))
+    }
+
+    pub fn load(source: &Source) -> Result<Input> {
+        match source {
+            Source::Stdin => Input::from_stdin(),
+            Source::File(path) => Input::from_file(path),
+            Source::Builtin(text) => Ok(Input::from_text(&source.name(), text)),
+            Source::Setting { value, .. } => Ok(Input::from_text(&source.name(), value))
+        }
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Input> {
+        let name = path.as_ref().display().to_string();
+        let io_error = |error| InputError::Io { file: name.clone(), error };
+
+        // Open the file
+        let file = File::open(&path).map_err(io_error)?;
+
+        // Memory map it
+        let mmap = unsafe { Mmap::map(&file).map_err(io_error)? };
+
+        // Drop the file
+        drop(file);
+
+        // Convert to text
+        let text = std::str::from_utf8(&mmap)
+            .map_err(|e| io_error(io::Error::new(io::ErrorKind::InvalidData, e)))?
+            .to_string();
+
+        Ok(Input { name, text })
+    }
+
+    pub fn from_stdin() -> Result<Input> {
+        let name = Source::Stdin.name();
+        let mut text = String::new();
+
+        io::stdin().read_to_string(&mut text).map_err(|error| InputError::Io { file: name.clone(), error })?;
+
+        Ok(Input { name, text })
+    }
+
+    pub fn from_text(name: &str, text: &str) -> Input {
+        Input {
+            name: name.to_string(),
+            text: text.to_string()
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    // All non-empty lines
+    pub fn lines(&self) -> Vec<String> {
+        self.numbered_lines().map(|(_, l)| l.to_string()).collect()
+    }
+
+    // All non-empty lines, each converted by the parse function. Errors name the line
+    pub fn parse_lines<T, E, F>(&self, mut parse: F) -> Result<Vec<T>>
+    where E: Into<LineError>, F: FnMut(&str) -> std::result::Result<T, E> {
+        self.numbered_lines().map(|(num, l)| {
+            parse(l).map_err(|error| self.parse_error(num, error))
+        }).collect()
+    }
+
+    // First non-empty line, converted by the parse function
+    pub fn parse_single_line<T, E, F>(&self, parse: F) -> Result<T>
+    where E: Into<LineError>, F: FnOnce(&str) -> std::result::Result<T, E> {
+        match self.numbered_lines().next() {
+            Some((num, l)) => parse(l).map_err(|error| self.parse_error(num, error)),
+            None => Err(InputError::Empty { file: self.name.clone() })
+        }
+    }
+
+    // First non-empty line
+    pub fn single_line(&self) -> Result<String> {
+        match self.numbered_lines().next() {
+            Some((_, l)) => Ok(l.to_string()),
+            None => Err(InputError::Empty { file: self.name.clone() })
+        }
+    }
+
+    // Non-empty lines as rows of cells, each char converted by the cell function
+    pub fn grid<T, F>(&self, mut cell: F) -> Result<Vec<Vec<T>>>
+    where F: FnMut(char) -> Option<T> {
+        let mut grid = Vec::new();
+
+        for (num, l) in self.numbered_lines() {
+            let mut row = Vec::with_capacity(l.len());
+
+            for (col, c) in l.chars().enumerate() {
+                match cell(c) {
+                    Some(t) => row.push(t),
+                    None => Err(self.parse_error(num, LineError::at(col + 1, &c.to_string(), "unexpected character")))?
+                }
+            }
+
+            grid.push(row);
+        }
+
+        if grid.is_empty() {
+            Err(InputError::Empty { file: self.name.clone() })?
+        }
+
+        Ok(grid)
+    }
+
+    pub fn parse_error<E: Into<LineError>>(&self, line: usize, error: E) -> InputError {
+        InputError::Parse {
+            file: self.name.clone(),
+            line,
+            error: error.into()
+        }
+    }
+
+    // Non-empty lines with 1-based line numbers
+    fn numbered_lines(&self) -> impl Iterator<Item = (usize, &str)> {
+        self.text.lines().enumerate().filter_map(|(idx, l)| {
+            if l.is_empty() {
+                None
+            } else {
+                Some((idx + 1, l))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines() {
+        let input = Input::from_text("test", "abc\n\ndef\r\nghi\n");
+
+        assert!(input.lines() == vec!["abc", "def", "ghi"]);
+        assert!(input.single_line().unwrap() == "abc");
+
+        let empty = Input::from_text("test", "\n\n");
+
+        assert!(empty.lines().is_empty());
+        assert!(matches!(empty.single_line(), Err(InputError::Empty { .. })));
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let input = Input::from_text("test", "1\n2\n\nx\n");
+
+        let result = input.parse_lines(|l| l.parse::<u8>().map_err(|e| e.to_string()));
+
+        match result {
+            Err(InputError::Parse { line, .. }) => assert!(line == 4),
+            _ => panic!("Expected parse error")
+        }
+    }
+
+    #[test]
+    fn test_grid() {
+        let input = Input::from_text("test", ".#\n#.\n");
+        let cell = |c| match c {
+            '.' => Some(false),
+            '#' => Some(true),
+            _ => None
+        };
+
+        assert!(input.grid(cell).unwrap() == vec![vec![false, true], vec![true, false]]);
+
+        let bad = Input::from_text("test", ".#\n#?\n");
+
+        assert!(bad.grid(cell).unwrap_err().to_string() == "test: line 2, column 2: unexpected character '?'");
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let err = Input::from_file("does-not-exist.txt").err().unwrap();
+
+        assert!(matches!(err, InputError::Io { .. }));
+        assert!(err.to_string().starts_with("does-not-exist.txt: "));
+    }
+
+    #[test]
+    fn test_unnamed() {
+        let input = Input::from("1\nx\n");
+
+        let err = input.parse_lines(|l| l.parse::<u8>().map_err(|e| e.to_string())).err().unwrap();
+
+        assert!(err.to_string() == "line 2: invalid digit found in string");
+    }
+}
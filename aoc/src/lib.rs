@@ -0,0 +1,7 @@
+pub mod answer;
+pub mod config;
+pub mod input;
+pub mod logging;
+pub mod parse;
+pub mod rng;
+pub mod solution;
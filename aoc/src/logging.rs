@@ -0,0 +1,112 @@
+use crate::solution::Result;
+use log::{LevelFilter, Log, Metadata, Record};
+use std::{cmp, env};
+
+// Diagnostics go through the log macros and are printed on stderr, so answers stay alone on stdout.
+// A spec is a level for everything and/or levels for crates, e.g. "debug" or "warn,day15=trace"
+
+pub const ENV_VAR: &str = "AOC_LOG";
+
+#[derive(Debug, Default)]
+pub struct Options {
+    spec: Option<String>
+}
+
+impl Options {
+    // Command line options handled by parse_arg
+    pub const USAGE: &'static str = "[--log SPEC] [-v|-vv]";
+
+    // Handle a command line option, calling value for its argument. Returns false if it isn't a logging option
+    pub fn parse_arg<F, S>(&mut self, arg: &str, value: F) -> Result<bool>
+    where F: FnOnce() -> std::result::Result<S, String>, S: AsRef<str> {
+        match arg {
+            "--log" => self.spec = Some(value()?.as_ref().to_string()),
+            "-v" => self.spec = Some("debug".to_string()),
+            "-vv" => self.spec = Some("trace".to_string()),
+            _ => return Ok(false)
+        }
+
+        Ok(true)
+    }
+
+    // Start logging with the spec from the command line, or else the environment
+    pub fn init(self) -> Result<()> {
+        let spec = match self.spec {
+            Some(spec) => spec,
+            None => env::var(ENV_VAR).unwrap_or_default()
+        };
+
+        let logger = Logger::parse(&spec)?;
+
+        log::set_max_level(logger.max_level());
+        log::set_boxed_logger(Box::new(logger)).map_err(|_| "Logging has already been started")?;
+
+        Ok(())
+    }
+}
+
+struct Logger {
+    default: LevelFilter,
+    crates: Vec<(String, LevelFilter)>
+}
+
+impl Logger {
+    fn parse(spec: &str) -> Result<Logger> {
+        let mut logger = Logger { default: LevelFilter::Off, crates: Vec::new() };
+
+        for item in spec.split(',').map(str::trim).filter(|i| !i.is_empty()) {
+            let level = |level: &str| level.parse::<LevelFilter>().map_err(|_| format!("Invalid log level {}", level));
+
+            match item.split_once('=') {
+                Some((name, lvl)) => logger.crates.push((name.trim().to_string(), level(lvl.trim())?)),
+                None => logger.default = level(item)?
+            }
+        }
+
+        Ok(logger)
+    }
+
+    fn max_level(&self) -> LevelFilter {
+        self.crates.iter().map(|(_, level)| *level).fold(self.default, cmp::max)
+    }
+
+    fn level_for(&self, target: &str) -> LevelFilter {
+        let name = target.split("::").next().unwrap_or(target);
+
+        self.crates.iter().find(|(n, _)| n == name).map_or(self.default, |(_, level)| *level)
+    }
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level_for(metadata.target())
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("[{} {}] {}", record.level(), record.target(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+#[test]
+fn test_spec() {
+    let logger = Logger::parse("warn, day15=trace,day04=off").unwrap();
+
+    assert!(logger.max_level() == LevelFilter::Trace);
+    assert!(logger.level_for("day15") == LevelFilter::Trace);
+    assert!(logger.level_for("day15::map") == LevelFilter::Trace);
+    assert!(logger.level_for("day04") == LevelFilter::Off);
+    assert!(logger.level_for("day05") == LevelFilter::Warn);
+
+    assert!(Logger::parse("").unwrap().max_level() == LevelFilter::Off);
+    assert!(Logger::parse("loud").is_err());
+    assert!(Logger::parse("day15=loud").is_err());
+
+    let mut options = Options::default();
+    assert!(options.parse_arg("-v", || Ok("")).unwrap());
+    assert!(options.spec.as_deref() == Some("debug"));
+    assert!(!options.parse_arg("--set", || Ok("x=1")).unwrap());
+}
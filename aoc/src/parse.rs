@@ -0,0 +1,279 @@
+use std::{fmt, str::FromStr};
+
+// An error within a single line of input, with the column and text at fault where known
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineError {
+    pub column: Option<usize>,
+    pub token: Option<String>,
+    pub message: String
+}
+
+impl LineError {
+    pub fn new(message: &str) -> LineError {
+        LineError {
+            column: None,
+            token: None,
+            message: message.to_string()
+        }
+    }
+
+    pub fn at(column: usize, token: &str, message: &str) -> LineError {
+        LineError {
+            column: Some(column),
+            token: Some(token.to_string()),
+            message: message.to_string()
+        }
+    }
+
+    // Something missing from the end of the line
+    pub fn at_end(line: &str, message: &str) -> LineError {
+        LineError {
+            column: Some(line.chars().count() + 1),
+            token: None,
+            message: message.to_string()
+        }
+    }
+}
+
+impl From<String> for LineError {
+    fn from(message: String) -> LineError {
+        LineError::new(&message)
+    }
+}
+
+impl From<&str> for LineError {
+    fn from(message: &str) -> LineError {
+        LineError::new(message)
+    }
+}
+
+impl fmt::Display for LineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(column) = self.column {
+            write!(f, "column {}: ", column)?;
+        }
+
+        f.write_str(&self.message)?;
+
+        if let Some(token) = &self.token {
+            write!(f, " '{}'", token)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub type Result<T> = std::result::Result<T, LineError>;
+
+// A piece of a line along with its 1-based column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub text: &'a str,
+    pub column: usize
+}
+
+impl<'a> Token<'a> {
+    // The whole of a line as a token
+    pub fn line(line: &'a str) -> Token<'a> {
+        Token { text: line, column: 1 }
+    }
+
+    pub fn error(&self, message: &str) -> LineError {
+        LineError::at(self.column, self.text, message)
+    }
+
+    pub fn parse<T: FromStr>(&self, what: &str) -> Result<T> {
+        self.text.parse::<T>().map_err(|_| self.error(&format!("invalid {}", what)))
+    }
+
+    // Single character token
+    pub fn char(&self, what: &str) -> Result<char> {
+        let mut chars = self.text.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(self.error(&format!("expected single character {}", what)))
+        }
+    }
+
+    // The rest of the token after a fixed prefix
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Token<'a>> {
+        match self.text.strip_prefix(prefix) {
+            Some(rest) => Ok(Token { text: rest, column: self.column + prefix.chars().count() }),
+            None => Err(self.error(&format!("expected '{}' at start of", prefix)))
+        }
+    }
+
+    // The token without a fixed suffix
+    pub fn strip_suffix(&self, suffix: &str) -> Result<Token<'a>> {
+        match self.text.strip_suffix(suffix) {
+            Some(rest) => Ok(Token { text: rest, column: self.column }),
+            None => Err(self.error(&format!("expected '{}' at end of", suffix)))
+        }
+    }
+
+    // Split the token in two at the last occurrence of a separator
+    pub fn rsplit_once(&self, sep: char) -> Option<(Token<'a>, Token<'a>)> {
+        let (first, second) = self.text.rsplit_once(sep)?;
+
+        Some((
+            Token { text: first, column: self.column },
+            Token { text: second, column: self.column + first.chars().count() + 1 }
+        ))
+    }
+
+    // Split the token in to pieces at a separator
+    pub fn split(&self, sep: char) -> Vec<Token<'a>> {
+        let mut column = self.column;
+
+        self.text.split(sep).map(|text| {
+            let token = Token { text, column };
+            column += text.chars().count() + 1;
+            token
+        }).collect()
+    }
+}
+
+// The whitespace separated words of a line
+pub struct Words<'a> {
+    line: &'a str,
+    words: Vec<Token<'a>>,
+    pos: usize
+}
+
+impl<'a> Words<'a> {
+    pub fn new(line: &'a str) -> Words<'a> {
+        let mut words = Vec::new();
+        let mut start = None;
+        let mut column = 0;
+
+        for (idx, c) in line.char_indices() {
+            column += 1;
+
+            match (c.is_whitespace(), start) {
+                (false, None) => start = Some((idx, column)),
+                (true, Some((s, col))) => {
+                    words.push(Token { text: &line[s..idx], column: col });
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+
+        if let Some((s, col)) = start {
+            words.push(Token { text: &line[s..], column: col });
+        }
+
+        Words {
+            line,
+            words,
+            pos: 0
+        }
+    }
+
+    // Next word, describing what was expected if the line has ended
+    pub fn word(&mut self, what: &str) -> Result<Token<'a>> {
+        self.next().ok_or_else(|| LineError::at_end(self.line, &format!("expected {}", what)))
+    }
+
+    // Next word, which must be the given keyword
+    pub fn keyword(&mut self, keyword: &str) -> Result<Token<'a>> {
+        let token = self.word(&format!("'{}'", keyword))?;
+
+        if token.text == keyword {
+            Ok(token)
+        } else {
+            Err(token.error(&format!("expected '{}', found", keyword)))
+        }
+    }
+
+    // Next words, which must be the given keywords
+    pub fn keywords(&mut self, keywords: &[&str]) -> Result<()> {
+        for keyword in keywords {
+            self.keyword(keyword)?;
+        }
+
+        Ok(())
+    }
+
+    // Parse the next word
+    pub fn parse<T: FromStr>(&mut self, what: &str) -> Result<T> {
+        self.word(what)?.parse(what)
+    }
+
+    // Parse the only word on the line
+    pub fn parse_only<T: FromStr>(mut self, what: &str) -> Result<T> {
+        let value = self.parse(what)?;
+
+        self.end()?;
+
+        Ok(value)
+    }
+
+    // Skip words
+    pub fn skip_words(&mut self, count: usize) -> Result<()> {
+        for _ in 0..count {
+            self.word("more words")?;
+        }
+
+        Ok(())
+    }
+
+    // Check nothing is left on the line
+    pub fn end(&self) -> Result<()> {
+        match self.words.get(self.pos) {
+            Some(token) => Err(token.error("unexpected")),
+            None => Ok(())
+        }
+    }
+}
+
+impl<'a> Iterator for Words<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        let token = self.words.get(self.pos).copied();
+
+        if token.is_some() {
+            self.pos += 1;
+        }
+
+        token
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_words() {
+        let mut words = Words::new("rotate  row y=12 by 4");
+
+        assert!(words.keyword("rotate").is_ok());
+        assert!(words.keyword("column").unwrap_err().to_string() == "column 9: expected 'column', found 'row'");
+
+        let y = words.word("row").unwrap().strip_prefix("y=").unwrap();
+        assert!(y == Token { text: "12", column: 15 });
+        assert!(y.parse::<u8>("row").unwrap() == 12);
+
+        assert!(words.keyword("by").is_ok());
+        assert!(words.parse::<u8>("shift").unwrap() == 4);
+        assert!(words.end().is_ok());
+        assert!(words.word("more").unwrap_err().to_string() == "column 22: expected more");
+    }
+
+    #[test]
+    fn test_token() {
+        let token = Token { text: "3x2x", column: 6 };
+
+        assert!(token.split('x') == vec![
+            Token { text: "3", column: 6 },
+            Token { text: "2", column: 8 },
+            Token { text: "", column: 10 }
+        ]);
+
+        assert!(token.parse::<u8>("size").unwrap_err().to_string() == "column 6: invalid size '3x2x'");
+        assert!(token.strip_suffix("x").unwrap().text == "3x2");
+    }
+}
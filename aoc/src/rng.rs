@@ -0,0 +1,94 @@
+use std::ops::RangeInclusive;
+
+// Small seeded random number generator (xorshift64*) for generating puzzle inputs.
+// Written out here so a seed gives the same input whatever crate versions are in use
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        // Mix the seed (splitmix64) so small seeds give unrelated sequences, and avoid the zero state
+        let mut z = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+
+        Rng { state: if z == 0 { 1 } else { z } }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+
+        self.state.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    // 0 to n - 1
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "Empty range");
+
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (lo, hi) = (*range.start(), *range.end());
+
+        assert!(lo <= hi, "Empty range");
+
+        match (hi - lo).checked_add(1) {
+            Some(n) => lo + ((self.next_u64() as u128 * n as u128) >> 64) as u64,
+            None => self.next_u64()
+        }
+    }
+
+    // True with probability num / den
+    pub fn chance(&mut self, num: usize, den: usize) -> bool {
+        self.below(den) < num
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+
+    // Random lower case letter
+    pub fn letter(&mut self) -> char {
+        (b'a' + self.below(26) as u8) as char
+    }
+
+    pub fn letters(&mut self, count: usize) -> String {
+        (0..count).map(|_| self.letter()).collect()
+    }
+}
+
+#[test]
+fn test_rng() {
+    let mut rng = Rng::new(0);
+    let first: Vec<u64> = (0..4).map(|_| rng.next_u64()).collect();
+
+    assert!(first.iter().all(|&n| n != 0));
+    assert!(Rng::new(0).next_u64() == first[0]);
+    assert!(Rng::new(1).next_u64() != first[0]);
+
+    for _ in 0..1000 {
+        assert!(rng.below(3) < 3);
+        assert!((5..=7).contains(&rng.range(5..=7)));
+    }
+
+    assert!(rng.range(0..=u64::MAX) != rng.range(0..=u64::MAX));
+
+    let mut items = [1, 2, 3, 4, 5];
+    rng.shuffle(&mut items);
+    items.sort();
+    assert!(items == [1, 2, 3, 4, 5]);
+
+    assert!(rng.letters(8).chars().all(|c| c.is_ascii_lowercase()));
+}
@@ -0,0 +1,117 @@
+use crate::{answer::{print_answer, Part}, config::{self, Config}, input::{Input, Source}, logging};
+use std::{env, error::Error, fmt::{self, Display}};
+
+pub type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+// An error from a solution, naming the input it was solving
+pub struct SolveError {
+    input: String,
+    error: Box<dyn Error>
+}
+
+impl SolveError {
+    fn wrap(input: &Input) -> impl Fn(Box<dyn Error>) -> SolveError + '_ {
+        move |error| SolveError { input: input.name().to_string(), error }
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.input, self.error)
+    }
+}
+
+// Debug is what main prints on error, so keep it readable
+impl fmt::Debug for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Error for SolveError {}
+
+// A day's puzzle. Each part takes the puzzle input text and returns its answer
+pub trait Solution {
+    type Part1: Display;
+    type Part2: Display;
+
+    fn part1(&self, input: &str) -> Result<Self::Part1>;
+
+    fn part2(&self, input: &str) -> Result<Self::Part2>;
+
+    // Just parse the input, so parsing can be timed on its own. Each part parses for itself too.
+    // Days which parse as they go leave this empty
+    fn parse(&self, _input: &str) -> Result<()> {
+        Ok(())
+    }
+
+    // Day 25 only has one part
+    fn has_part2(&self) -> bool {
+        true
+    }
+}
+
+// Solve and print the selected parts (None is all parts)
+pub fn run<S: Solution>(solution: &S, input: &Input, part: Option<Part>) -> Result<()> {
+    if Part::One.wanted(part) {
+        print_answer(Part::One, solution.part1(input.text()).map_err(SolveError::wrap(input))?);
+    }
+
+    if Part::Two.wanted(part) {
+        if solution.has_part2() {
+            print_answer(Part::Two, solution.part2(input.text()).map_err(SolveError::wrap(input))?);
+        } else if part.is_some() {
+            Err("This day only has one part")?
+        }
+    }
+
+    Ok(())
+}
+
+// Solve a single part, returning the answer as text
+pub fn solve<S: Solution>(solution: &S, input: &Input, part: Part) -> Result<String> {
+    let answer = match part {
+        Part::One => solution.part1(input.text()).map(|a| a.to_string()),
+        Part::Two => solution.part2(input.text()).map(|a| a.to_string())
+    };
+
+    Ok(answer.map_err(SolveError::wrap(input))?)
+}
+
+// Body of each day's binary: [INPUT] [config and logging options]. Solves both parts
+pub fn main<S: Solution>(solution: S, default: fn() -> Source) -> Result<()> {
+    let input = parse_args(default, "", |_, _| Ok(false))?;
+
+    run(&solution, &input, None)
+}
+
+// Handle a day binary's command line, setting the config and logging and loading the input.
+// extra handles any other options, like parse_arg does. The default input is only looked up after the config is set
+pub fn parse_args<F>(default: fn() -> Source, extra_usage: &str, mut extra: F) -> Result<Input>
+where F: FnMut(&str, &mut dyn FnMut() -> std::result::Result<String, String>) -> Result<bool> {
+    let mut args = env::args().skip(1);
+    let mut config = Config::default();
+    let mut log_options = logging::Options::default();
+    let mut source = None;
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("{} needs a value", arg));
+
+        if extra(&arg, &mut value)? || config.parse_arg(&arg, &mut value)? || log_options.parse_arg(&arg, &mut value)? {
+            continue
+        }
+
+        if source.is_none() && !arg.starts_with("--") {
+            source = Some(Source::from_arg(&arg))
+        } else {
+            let usage: Vec<&str> = [extra_usage, Config::USAGE, logging::Options::USAGE].iter().copied().filter(|u| !u.is_empty()).collect();
+
+            Err(format!("Unrecognised argument {}\nUsage: [INPUT] {}", arg, usage.join(" ")))?
+        }
+    }
+
+    config::set_config(config);
+    log_options.init()?;
+
+    Ok(Input::load(&source.unwrap_or_else(default))?)
+}
@@ -0,0 +1,35 @@
+use aoc::{answer::Part, input::Input};
+use aoc2016::DAYS;
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::env;
+
+// Parsing and each part of every day on its checked-in input.
+// Run some with a filter, e.g. cargo bench -p aoc2016 -- day16
+fn days(c: &mut Criterion) {
+    // Inputs are named relative to the workspace
+    env::set_current_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/..")).unwrap();
+
+    for (idx, day) in DAYS.iter().enumerate() {
+        let input = Input::load(&(day.default_input)()).unwrap();
+
+        // The slow days take seconds a run, so keep to the fewest samples
+        let mut group = c.benchmark_group(format!("day{:02}", idx + 1));
+        group.sample_size(10);
+
+        group.bench_function("parse", |b| b.iter(|| (day.parse)(&input).unwrap()));
+
+        for part in day.parts() {
+            let name = match part {
+                Part::One => "part1",
+                Part::Two => "part2"
+            };
+
+            group.bench_function(name, |b| b.iter(|| (day.solve)(&input, part).unwrap()));
+        }
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
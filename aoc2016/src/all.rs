@@ -0,0 +1,110 @@
+use crate::time::format_duration;
+use aoc::{answer::Part, input::Input};
+use aoc2016::{panic_message, Day, DAYS};
+use rayon::prelude::*;
+use std::{error::Error, panic::{self, AssertUnwindSafe}, time::{Duration, Instant}};
+
+// One part of one day
+struct Job {
+    day_num: usize,
+    part: Part
+}
+
+struct Outcome {
+    day_num: usize,
+    part: Part,
+    answer: Result<String, String>,
+    time: Duration
+}
+
+// Solve every part of every day on a thread pool, then print a summary. [--threads N]
+pub fn all(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut threads = 0;
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--threads" => {
+                let value = args.next().ok_or("--threads needs a value")?;
+                threads = value.parse().map_err(|_| format!("Invalid thread count {}", value))?;
+            }
+            _ => Err(format!("Unrecognised option {}", arg))?
+        }
+    }
+
+    // Zero threads lets rayon choose
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(threads).build()?;
+
+    let jobs: Vec<Job> = DAYS.iter().enumerate().flat_map(|(idx, day)| {
+        day.parts().into_iter().map(move |part| Job { day_num: idx + 1, part })
+    }).collect();
+
+    let start = Instant::now();
+    let outcomes: Vec<Outcome> = pool.install(|| jobs.par_iter().map(run_job).collect());
+    let elapsed = start.elapsed();
+
+    let failed = print_summary(&outcomes);
+
+    let threads = pool.current_num_threads();
+
+    println!("{} parts solved, {} failed in {} ({} total on {} thread{})", outcomes.len() - failed, failed, format_duration(elapsed),
+        format_duration(outcomes.iter().map(|o| o.time).sum()), threads, if threads == 1 { "" } else { "s" });
+
+    if failed > 0 {
+        Err(format!("{} parts failed", failed))?
+    }
+
+    Ok(())
+}
+
+// Load the input and solve, catching panics so other days carry on
+fn run_job(job: &Job) -> Outcome {
+    let day: &Day = &DAYS[job.day_num - 1];
+    let start = Instant::now();
+
+    let answer = match panic::catch_unwind(AssertUnwindSafe(|| -> Result<String, Box<dyn Error>> {
+        let input = Input::load(&(day.default_input)())?;
+
+        (day.solve)(&input, job.part)
+    })) {
+        Ok(Ok(answer)) => Ok(answer),
+        Ok(Err(e)) => Err(e.to_string()),
+        Err(payload) => Err(panic_message(payload.as_ref()))
+    };
+
+    Outcome { day_num: job.day_num, part: job.part, answer, time: start.elapsed() }
+}
+
+// Prints the table, then any multi-line answers. Returns the number of failures
+fn print_summary(outcomes: &[Outcome]) -> usize {
+    let mut failed = 0;
+    let mut long_answers = Vec::new();
+
+    println!("{:<4} {:<4} {:>10}  Answer", "Day", "Part", "Time");
+
+    for outcome in outcomes {
+        let answer = match &outcome.answer {
+            Ok(answer) if answer.contains('\n') => {
+                long_answers.push(outcome);
+                "(below)".to_string()
+            }
+            Ok(answer) => answer.clone(),
+            Err(e) => {
+                failed += 1;
+                format!("FAIL ({})", e)
+            }
+        };
+
+        println!("{:<4} {:<4} {:>10}  {}", format!("{:02}", outcome.day_num), outcome.part, format_duration(outcome.time), answer);
+    }
+
+    for outcome in long_answers {
+        if let Ok(answer) = &outcome.answer {
+            println!("\nDay {:02} part {}:\n{}", outcome.day_num, outcome.part, answer.trim_end());
+        }
+    }
+
+    println!();
+
+    failed
+}
@@ -0,0 +1,152 @@
+use crate::time::format_duration;
+use aoc::{answer::Part, config::{self, Config}, input::Input};
+use aoc2016::{lookup_day, panic_message, Day, DAYS};
+use std::{cmp, error::Error, fs, panic::{self, AssertUnwindSafe}, path::{Path, PathBuf}, time::{Duration, Instant}};
+
+// One input's answers, or errors, for the selected parts
+struct Row {
+    name: String,
+    answers: Vec<(Part, Result<String, String>)>,
+    time: Duration
+}
+
+// Solve a day for every input in the directories, globs or files given, one row per input.
+// <day> <DIR|GLOB|FILE>... [--part 1|2] [--config PATH] [--set KEY=VALUE]
+pub fn batch(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut args = args.iter();
+
+    let day_num = lookup_day(args.next().ok_or("batch needs a day")?)?;
+    let day = &DAYS[day_num - 1];
+
+    let mut part = None;
+    let mut patterns = Vec::new();
+    let mut config = Config::default();
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("{} needs a value", arg));
+
+        if config.parse_arg(arg, &mut value)? {
+            continue
+        }
+
+        match arg.as_str() {
+            "--part" => {
+                let value = value()?;
+                part = Some(Part::parse(value).ok_or_else(|| format!("Invalid part {}", value))?);
+            }
+            _ if arg.starts_with("--") => Err(format!("Unrecognised option {}", arg))?,
+            _ => patterns.push(arg.as_str())
+        }
+    }
+
+    if patterns.is_empty() {
+        Err("batch needs a directory, glob or file of inputs")?
+    }
+
+    let parts: Vec<Part> = day.parts().into_iter().filter(|p| p.wanted(part)).collect();
+
+    if parts.is_empty() {
+        Err(format!("Day {} only has one part", day_num))?
+    }
+
+    let mut paths = Vec::new();
+
+    for pattern in patterns {
+        paths.extend(expand(pattern)?);
+    }
+
+    config::set_config(config);
+
+    let rows: Vec<Row> = paths.iter().map(|path| solve_input(day, path, &parts)).collect();
+
+    let failed = print_rows(day_num, &parts, &rows);
+
+    if failed > 0 {
+        Err(format!("{} answers failed", failed))?
+    }
+
+    Ok(())
+}
+
+// The files in a directory, matching a glob, or just the file itself
+fn expand(pattern: &str) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let path = Path::new(pattern);
+
+    let mut paths: Vec<PathBuf> = if path.is_dir() {
+        fs::read_dir(path)?.map(|entry| entry.map(|e| e.path())).collect::<Result<_, _>>()?
+    } else if path.is_file() {
+        vec![path.to_path_buf()]
+    } else {
+        glob::glob(pattern).map_err(|e| format!("Invalid glob {}: {}", pattern, e))?.collect::<Result<_, _>>()?
+    };
+
+    paths.retain(|p| p.is_file());
+    paths.sort();
+
+    if paths.is_empty() {
+        Err(format!("No inputs found for {}", pattern))?
+    }
+
+    Ok(paths)
+}
+
+// Solve the parts for one input, catching panics so the other inputs carry on
+fn solve_input(day: &Day, path: &Path, parts: &[Part]) -> Row {
+    let start = Instant::now();
+
+    let answers = match Input::from_file(path) {
+        Ok(input) => parts.iter().map(|&part| {
+            let answer = match panic::catch_unwind(AssertUnwindSafe(|| (day.solve)(&input, part))) {
+                Ok(Ok(answer)) => Ok(answer),
+                Ok(Err(e)) => Err(e.to_string()),
+                Err(payload) => Err(panic_message(payload.as_ref()))
+            };
+
+            (part, answer)
+        }).collect(),
+        Err(e) => parts.iter().map(|&part| (part, Err(e.to_string()))).collect()
+    };
+
+    Row { name: path.display().to_string(), answers, time: start.elapsed() }
+}
+
+// Prints the table, then failures and multi-line answers. Returns the number of failures
+fn print_rows(day_num: usize, parts: &[Part], rows: &[Row]) -> usize {
+    let cell = |answer: &Result<String, String>| match answer {
+        Ok(answer) if answer.contains('\n') => "(below)".to_string(),
+        Ok(answer) => answer.clone(),
+        Err(_) => "FAIL".to_string()
+    };
+
+    let name_width = rows.iter().map(|r| r.name.len()).fold("Input".len(), cmp::max);
+    let answer_width = rows.iter().flat_map(|r| r.answers.iter().map(|(_, a)| cell(a).len())).fold("Part 1".len(), cmp::max);
+
+    println!("Day {:02}", day_num);
+
+    let headings: String = parts.iter().map(|p| format!("{:<1$}  ", format!("Part {}", p), answer_width)).collect();
+    println!("{:<name_width$}  {}{:>10}", "Input", headings, "Time", name_width = name_width);
+
+    for row in rows {
+        let cells: String = row.answers.iter().map(|(_, a)| format!("{:<1$}  ", cell(a), answer_width)).collect();
+        println!("{:<name_width$}  {}{:>10}", row.name, cells, format_duration(row.time), name_width = name_width);
+    }
+
+    let mut failed = 0;
+
+    for row in rows {
+        for (part, answer) in &row.answers {
+            match answer {
+                Ok(answer) if answer.contains('\n') => println!("\n{} part {}:\n{}", row.name, part, answer.trim_end()),
+                Ok(_) => (),
+                Err(e) => {
+                    println!("\n{} part {}: FAIL ({})", row.name, part, e);
+                    failed += 1;
+                }
+            }
+        }
+    }
+
+    println!();
+
+    failed
+}
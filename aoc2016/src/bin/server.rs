@@ -0,0 +1,292 @@
+use aoc::{answer::Part, input::Input, logging};
+use aoc2016::{lookup_day, panic_message, DAYS};
+use serde_json::{json, Value};
+use std::{env, error::Error, io::{self, BufRead, BufReader, Read, Write}, panic::{self, AssertUnwindSafe}, path::{Path, PathBuf},
+    process::{Child, Command, Stdio}, sync::{atomic::{AtomicUsize, Ordering}, mpsc::{self, RecvTimeoutError}, Arc}, thread, time::{Duration, Instant}};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+// Serves the solvers on localhost. POST /day/N[?part=1|2] with the puzzle input as the body
+// and the answers come back as JSON. Each input is solved by running this program again with
+// --worker, so a solve which overruns the time limit can be killed
+
+const USAGE: &str = "Usage: aoc2016-server [--port N] [--time-limit SECS] [--max-running N]";
+
+const PORT: u16 = 2016;
+const TIME_LIMIT: f64 = 10.0;
+const MAX_BODY: u64 = 1 << 20;
+
+// Worker processes to run at once
+const MAX_RUNNING: usize = 4;
+
+#[derive(Clone)]
+struct Limits {
+    time_limit: Duration,
+    max_running: usize,
+    running: Arc<AtomicUsize>,
+    worker: PathBuf
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut port = PORT;
+    let mut time_limit = TIME_LIMIT;
+    let mut max_running = MAX_RUNNING;
+
+    let mut args = env::args().skip(1).peekable();
+
+    if args.peek().map(String::as_str) == Some("--worker") {
+        args.next();
+        return worker(args.collect())
+    }
+
+    while let Some(arg) = args.next() {
+        let value = args.next().ok_or_else(|| format!("{} needs a value\n{}", arg, USAGE))?;
+        let invalid = || format!("Invalid value {} for {}", value, arg);
+
+        match arg.as_str() {
+            "--port" => port = value.parse().map_err(|_| invalid())?,
+            "--time-limit" => time_limit = value.parse().ok().filter(|&t: &f64| t > 0.0 && t.is_finite()).ok_or_else(invalid)?,
+            "--max-running" => max_running = value.parse().ok().filter(|&n| n > 0).ok_or_else(invalid)?,
+            _ => Err(format!("Unrecognised option {}\n{}", arg, USAGE))?
+        }
+    }
+
+    // Traces come from the AOC_LOG environment variable
+    logging::Options::default().init()?;
+
+    let limits = Limits {
+        time_limit: Duration::from_secs_f64(time_limit),
+        max_running,
+        running: Arc::new(AtomicUsize::new(0)),
+        worker: env::current_exe()?
+    };
+
+    let server = Server::http(("127.0.0.1", port)).map_err(|e| format!("Can't listen on port {}: {}", port, e))?;
+
+    // Port 0 picks a free port
+    let port = server.server_addr().to_ip().map_or(port, |addr| addr.port());
+
+    println!("Listening on http://127.0.0.1:{}", port);
+
+    for request in server.incoming_requests() {
+        let limits = limits.clone();
+
+        thread::spawn(move || respond(request, &limits));
+    }
+
+    Ok(())
+}
+
+fn respond(mut request: Request, limits: &Limits) {
+    let mut body = String::new();
+
+    let (status, json) = match request.as_reader().take(MAX_BODY + 1).read_to_string(&mut body) {
+        Ok(_) if body.len() as u64 > MAX_BODY => (413, error_json(&format!("Input is more than {} bytes", MAX_BODY))),
+        Ok(_) => handle(request.method(), request.url(), &body, limits),
+        Err(_) => (400, error_json("Input must be UTF-8 text"))
+    };
+
+    let header = Header::from_bytes("Content-Type", "application/json").expect("valid header");
+    let response = Response::from_string(format!("{}\n", json)).with_status_code(status).with_header(header);
+
+    // Nothing more to do if the client has gone
+    let _ = request.respond(response);
+}
+
+fn error_json(message: &str) -> Value {
+    json!({ "error": message })
+}
+
+// Returns the status code and JSON body for a request
+fn handle(method: &Method, url: &str, body: &str, limits: &Limits) -> (u16, Value) {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+
+    let day_num = match path.strip_prefix("/day/") {
+        Some(day) => match lookup_day(day) {
+            Ok(day_num) => day_num,
+            Err(e) => return (404, error_json(&e))
+        },
+        None => return (404, error_json("Not found (expecting POST /day/N)"))
+    };
+
+    if *method != Method::Post {
+        return (405, error_json("Only POST is supported"))
+    }
+
+    let part = match query {
+        "" => None,
+        _ => match query.strip_prefix("part=").and_then(Part::parse) {
+            Some(part) => Some(part),
+            None => return (400, error_json(&format!("Invalid query {} (expecting part=1 or part=2)", query)))
+        }
+    };
+
+    solve(day_num, part, body, limits)
+}
+
+// Solve in a worker process, reporting whatever finishes within the time limit. The worker is
+// killed once the response is ready, so it can't hold on to its place after the time limit
+fn solve(day_num: usize, part: Option<Part>, text: &str, limits: &Limits) -> (u16, Value) {
+    let day = &DAYS[day_num - 1];
+
+    let parts: Vec<Part> = day.parts().into_iter().filter(|p| p.wanted(part)).collect();
+
+    if parts.is_empty() {
+        return (400, error_json(&format!("Day {} only has one part", day_num)))
+    }
+
+    if limits.running.fetch_add(1, Ordering::SeqCst) >= limits.max_running {
+        limits.running.fetch_sub(1, Ordering::SeqCst);
+        return (503, error_json("Too many inputs are being solved, try again later"))
+    }
+
+    let deadline = Instant::now() + limits.time_limit;
+
+    let response = match start_worker(day_num, part, text, &limits.worker) {
+        Ok(mut child) => {
+            let response = collect(day_num, &parts, &mut child, deadline, limits.time_limit);
+
+            // It may have finished already
+            let _ = child.kill();
+            let _ = child.wait();
+
+            response
+        }
+        Err(e) => (500, error_json(&format!("Can't start a worker: {}", e)))
+    };
+
+    limits.running.fetch_sub(1, Ordering::SeqCst);
+
+    response
+}
+
+// Run this program with --worker, giving it the input
+fn start_worker(day_num: usize, part: Option<Part>, text: &str, worker: &Path) -> io::Result<Child> {
+    let mut command = Command::new(worker);
+
+    command.arg("--worker").arg(day_num.to_string()).args(part.map(|p| part_num(p).to_string()));
+
+    let mut child = command.stdin(Stdio::piped()).stdout(Stdio::piped()).spawn()?;
+
+    // The worker reads all of its input before starting, and it's an error if it stops early
+    let _ = child.stdin.take().expect("piped stdin").write_all(text.as_bytes());
+
+    Ok(child)
+}
+
+// Read the worker's reports, one JSON object per line, until every part is answered or the deadline passes
+fn collect(day_num: usize, parts: &[Part], child: &mut Child, deadline: Instant, time_limit: Duration) -> (u16, Value) {
+    let (sender, receiver) = mpsc::channel();
+    let reader = BufReader::new(child.stdout.take().expect("piped stdout"));
+
+    // Ends when the worker does
+    thread::spawn(move || {
+        for line in reader.lines().map_while(|line| line.ok()) {
+            if sender.send(serde_json::from_str::<Value>(&line)).is_err() {
+                break
+            }
+        }
+    });
+
+    let mut answers = Vec::new();
+    let mut parse_time = None;
+
+    for &part in parts {
+        let answer = loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+
+            match receiver.recv_timeout(remaining) {
+                Ok(Ok(report)) if report.get("part").is_some() => break report,
+                Ok(Ok(report)) if report.get("error").is_some() => {
+                    return (422, json!({ "day": day_num, "error": report["error"], "parse_time": report["parse_time"] }))
+                }
+                Ok(Ok(report)) => parse_time = Some(report["parse_time"].clone()),
+                Ok(Err(e)) => break json!({ "part": part_num(part), "error": format!("Invalid report from worker: {}", e) }),
+                Err(RecvTimeoutError::Timeout) => break json!({ "part": part_num(part), "error": format!("Time limit of {:?} exceeded", time_limit) }),
+                Err(RecvTimeoutError::Disconnected) => break json!({ "part": part_num(part), "error": "Worker stopped without an answer" })
+            }
+        };
+
+        answers.push(answer);
+    }
+
+    (200, json!({ "day": day_num, "parse_time": parse_time, "answers": answers }))
+}
+
+// Parse and solve the input on stdin, writing a JSON line for the parse and one for each part
+fn worker(args: Vec<String>) -> Result<(), Box<dyn Error>> {
+    let (day_num, part) = match &args[..] {
+        [day] => (lookup_day(day)?, None),
+        [day, part] => (lookup_day(day)?, Some(Part::parse(part).ok_or_else(|| format!("Invalid part {}", part))?)),
+        _ => Err(USAGE)?
+    };
+
+    logging::Options::default().init()?;
+
+    let mut text = String::new();
+    io::stdin().read_to_string(&mut text)?;
+
+    let day = &DAYS[day_num - 1];
+    let input = Input::from_text(&format!("day {} request", day_num), &text);
+    let mut stdout = io::stdout();
+
+    let (parsed, time) = timed(|| (day.parse)(&input));
+
+    if let Err(e) = parsed {
+        writeln!(stdout, "{}", json!({ "error": e, "parse_time": time.as_secs_f64() }))?;
+        return Ok(())
+    }
+
+    writeln!(stdout, "{}", json!({ "parse_time": time.as_secs_f64() }))?;
+
+    for part in day.parts().into_iter().filter(|p| p.wanted(part)) {
+        let report = match timed(|| (day.solve)(&input, part)) {
+            (Ok(answer), time) => json!({ "part": part_num(part), "answer": answer, "time": time.as_secs_f64() }),
+            (Err(e), time) => json!({ "part": part_num(part), "error": e, "time": time.as_secs_f64() })
+        };
+
+        writeln!(stdout, "{}", report)?;
+    }
+
+    Ok(())
+}
+
+// Run f, turning errors and panics into messages
+fn timed<T, F>(f: F) -> (Result<T, String>, Duration)
+where F: FnOnce() -> Result<T, Box<dyn Error>> {
+    let start = Instant::now();
+
+    let result = match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => result.map_err(|e| e.to_string()),
+        Err(payload) => Err(panic_message(payload.as_ref()))
+    };
+
+    (result, start.elapsed())
+}
+
+fn part_num(part: Part) -> u8 {
+    match part {
+        Part::One => 1,
+        Part::Two => 2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Requests which are turned away before any solving. tests/server.rs covers the rest
+    #[test]
+    fn test_handle() {
+        let limits = Limits { time_limit: Duration::from_secs(10), max_running: MAX_RUNNING, running: Arc::new(AtomicUsize::new(0)), worker: PathBuf::new() };
+
+        assert!(handle(&Method::Get, "/day/1", "", &limits).0 == 405);
+        assert!(handle(&Method::Post, "/day/26", "", &limits).0 == 404);
+        assert!(handle(&Method::Post, "/days", "", &limits).0 == 404);
+        assert!(handle(&Method::Post, "/day/1?part=3", "", &limits).0 == 400);
+        assert!(handle(&Method::Post, "/day/25?part=2", "", &limits).0 == 400);
+
+        // No slots left
+        limits.running.store(MAX_RUNNING, Ordering::SeqCst);
+        assert!(handle(&Method::Post, "/day/1", "R2\n", &limits).0 == 503);
+    }
+}
@@ -0,0 +1,134 @@
+use aoc::{answer::Part, input::{Input, Source}, rng::Rng, solution::{self, Solution}};
+use std::{any::Any, error::Error};
+
+type RunFn = fn(&Input, Option<Part>) -> Result<(), Box<dyn Error>>;
+type SolveFn = fn(&Input, Part) -> Result<String, Box<dyn Error>>;
+type ParseFn = fn(&Input) -> Result<(), Box<dyn Error>>;
+
+// A day's solution behind plain functions, so the days can go in one table
+pub struct Day {
+    pub run: RunFn,
+    pub solve: SolveFn,
+    pub parse: ParseFn,
+    pub has_part2: fn() -> bool,
+    pub default_input: fn() -> Source,
+    pub generate: fn(&mut Rng) -> String
+}
+
+impl Day {
+    // Random input which the same seed always reproduces
+    pub fn generated_input(&self, day_num: usize, seed: u64) -> Input {
+        let text = (self.generate)(&mut Rng::new(seed));
+
+        Input::from_text(&format!("day {} seed {}", day_num, seed), &text)
+    }
+
+    // The parts this day has
+    pub fn parts(&self) -> Vec<Part> {
+        if (self.has_part2)() {
+            vec![Part::One, Part::Two]
+        } else {
+            vec![Part::One]
+        }
+    }
+}
+
+macro_rules! day {
+    ($day:ident, $solution:ident) => {
+        Day {
+            run: |input, part| solution::run(&$day::$solution, input, part),
+            solve: |input, part| solution::solve(&$day::$solution, input, part),
+            parse: |input| $day::$solution.parse(input.text()),
+            has_part2: || $day::$solution.has_part2(),
+            default_input: $day::default_input,
+            generate: $day::generate
+        }
+    };
+}
+
+pub static DAYS: [Day; 25] = [
+    day!(day01, Day01), day!(day02, Day02), day!(day03, Day03), day!(day04, Day04), day!(day05, Day05),
+    day!(day06, Day06), day!(day07, Day07), day!(day08, Day08), day!(day09, Day09), day!(day10, Day10),
+    day!(day11, Day11), day!(day12, Day12), day!(day13, Day13), day!(day14, Day14), day!(day15, Day15),
+    day!(day16, Day16), day!(day17, Day17), day!(day18, Day18), day!(day19, Day19), day!(day20, Day20),
+    day!(day21, Day21), day!(day22, Day22), day!(day23, Day23), day!(day24, Day24), day!(day25, Day25),
+];
+
+pub fn lookup_day(day_num: &str) -> Result<usize, String> {
+    match day_num.parse::<usize>() {
+        Ok(num) if (1..=DAYS.len()).contains(&num) => Ok(num),
+        _ => Err(format!("Invalid day {} (expecting 1 to {})", day_num, DAYS.len()))
+    }
+}
+
+// Day numbers from the command line, or all of them
+pub fn day_nums(args: &[String]) -> Result<Vec<usize>, String> {
+    if args.is_empty() {
+        Ok((1..=DAYS.len()).collect())
+    } else {
+        args.iter().map(|a| lookup_day(a)).collect()
+    }
+}
+
+// The message from a caught panic
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match payload.downcast_ref::<&str>() {
+        Some(message) => format!("panicked: {}", message),
+        None => match payload.downcast_ref::<String>() {
+            Some(message) => format!("panicked: {}", message),
+            None => "panicked".to_string()
+        }
+    }
+}
+
+#[test]
+fn test_generate() {
+    // Parts too slow for a debug build
+    let slow = [(5, Part::One), (5, Part::Two), (14, Part::One), (14, Part::Two), (18, Part::Two), (23, Part::Two)];
+
+    for (day_num, day) in DAYS.iter().enumerate().map(|(i, day)| (i + 1, day)) {
+        for seed in 0..3 {
+            let input = day.generated_input(day_num, seed);
+
+            assert!(input.text() == day.generated_input(day_num, seed).text());
+            assert!((day.parse)(&input).is_ok(), "day {} seed {} doesn't parse", day_num, seed);
+
+            for part in day.parts().into_iter().filter(|&part| !slow.contains(&(day_num, part))) {
+                assert!((day.solve)(&input, part).is_ok(), "day {} seed {} part {:?} failed", day_num, seed, part);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_bad_input() {
+    // Inputs which used to panic or hang, and the errors they give now
+    let cases = [
+        (4, Part::One, "ab1c-123[abcde]\n", "invalid character in room name '1'"),
+        (10, Part::One, "value 1 goes to bot 1\nvalue 2 goes to bot 1\nbot 1 gives low to bot 2 and high to bot 2\nbot 2 gives low to bot 1 and high to bot 1\n", "in a loop"),
+        (11, Part::One, "The first floor contains nothing relevant.\nThe second floor contains a curium-compatible microchip.\nThe third floor contains nothing relevant.\nThe fourth floor contains nothing relevant.\n", "No solution"),
+        (11, Part::One, "The first floor contains a curium generator and a curium generator.\nx\ny\nz\n", "listed more than once"),
+        (15, Part::One, "Disc #1 has 2 positions; at time=0, it is at position 0.\nDisc #2 has 4 positions; at time=0, it is at position 0.\n", "never line up"),
+        (20, Part::One, "0-4294967295\n", "Every address is blocked"),
+        (22, Part::Two, "/dev/grid/node-x0-y0 10T 5T 5T 50%\n/dev/grid/node-x2-y0 10T 0T 10T 0%\n", "Node x1-y0 is missing"),
+        (22, Part::Two, "/dev/grid/node-x0-y0 10T 8T 2T 80%\n/dev/grid/node-x1-y0 500T 490T 10T 98%\n/dev/grid/node-x2-y0 10T 7T 3T 70%\n\
+            /dev/grid/node-x0-y1 10T 6T 4T 60%\n/dev/grid/node-x1-y1 10T 0T 10T 0%\n/dev/grid/node-x2-y1 10T 6T 4T 60%\n", "can't reach 1,0"),
+        (22, Part::Two, "/dev/grid/node-x0-y0 10T 5T 5T 50%\n/dev/grid/node-x1-y0 10T 5T 5T 50%\n", "No empty node"),
+        (22, Part::Two, "/dev/grid/node-x0-y0 10T 0T 10T 0%\n/dev/grid/node-x1-y0 30T 20T 10T 66%\n", "doesn't fit in the empty node"),
+        (24, Part::One, "#####\n#0#1#\n#####\n", "can be reached"),
+        (24, Part::One, "#####\n#0.2#\n#####\n", "Item 1 is missing"),
+        (25, Part::One, "", "gives a clock signal within"),
+        (25, Part::One, "out a\n", "gives a clock signal within"),
+    ];
+
+    for (day_num, part, text, expected) in cases.iter() {
+        let input = Input::from_text("bad", text);
+        let error = (DAYS[day_num - 1].solve)(&input, *part).unwrap_err().to_string();
+
+        assert!(error.contains(expected), "Day {} gave {}", day_num, error);
+    }
+
+    // Too short to hold an ABBA or ABA
+    let input = Input::from_text("short", "ab[c]d\n");
+    assert!((DAYS[6].solve)(&input, Part::One).unwrap() == "0");
+}
@@ -0,0 +1,112 @@
+mod all;
+mod batch;
+mod time;
+mod verify;
+
+use aoc::{answer::Part, config::{self, Config}, input::{Input, Source}, logging};
+use aoc2016::{lookup_day, DAYS};
+use std::{env, error::Error};
+
+const USAGE: &str = "Usage: aoc2016 run <day> [--part 1|2] [--input PATH | --seed N] [--gif PATH] [--png PATH] [--svg PATH] [--animate] [--fps N]
+                        [--config PATH] [--set KEY=VALUE] [--log SPEC] [-v|-vv]
+       aoc2016 generate <day> [--seed N]
+       aoc2016 verify [<day>...]
+       aoc2016 time [<day>...]
+       aoc2016 all [--threads N]
+       aoc2016 batch <day> <DIR|GLOB|FILE>... [--part 1|2] [--config PATH] [--set KEY=VALUE]
+
+Logging for other commands is set by the AOC_LOG environment variable (e.g. AOC_LOG=debug or AOC_LOG=day15=trace)";
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let command = args.first().map(|a| a.as_str());
+
+    // run takes logging options of its own
+    if command != Some("run") {
+        logging::Options::default().init()?;
+    }
+
+    match command {
+        Some("run") => run(&args[1..]),
+        Some("generate") => generate(&args[1..]),
+        Some("verify") => verify::verify(&args[1..]),
+        Some("time") => time::time(&args[1..]),
+        Some("all") => all::all(&args[1..]),
+        Some("batch") => batch::batch(&args[1..]),
+        _ => Err(USAGE)?
+    }
+}
+
+fn run(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut args = args.iter();
+
+    // Get day number
+    let day_num = lookup_day(args.next().ok_or(USAGE)?)?;
+    let day = &DAYS[day_num - 1];
+
+    // Process options
+    let mut part = None;
+    let mut source = None;
+    let mut seed = None;
+    let mut render_options = render::Options::default();
+    let mut config = Config::default();
+    let mut log_options = logging::Options::default();
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("{} needs a value\n{}", arg, USAGE));
+
+        if render_options.parse_arg(arg, &mut value)? || config.parse_arg(arg, &mut value)? || log_options.parse_arg(arg, &mut value)? {
+            continue
+        }
+
+        match arg.as_str() {
+            "--part" => {
+                let value = value()?;
+                part = Some(Part::parse(value).ok_or_else(|| format!("Invalid part {}", value))?);
+            }
+            "--input" => source = Some(Source::from_arg(value()?)),
+            "--seed" => seed = Some(parse_seed(value()?)?),
+            _ => Err(format!("Unrecognised option {}\n{}", arg, USAGE))?
+        }
+    }
+
+    render::set_options(render_options);
+    config::set_config(config);
+    log_options.init()?;
+
+    // Load or generate the input
+    let input = match (source, seed) {
+        (Some(_), Some(_)) => Err(format!("--input and --seed can't be used together\n{}", USAGE))?,
+        (None, Some(seed)) => day.generated_input(day_num, seed),
+        (source, None) => Input::load(&source.unwrap_or_else(day.default_input))?
+    };
+
+    // Run the day
+    println!("Day {:02}", day_num);
+
+    (day.run)(&input, part)
+}
+
+// Print a generated input for a day
+fn generate(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut args = args.iter();
+
+    let day_num = lookup_day(args.next().ok_or(USAGE)?)?;
+    let mut seed = 0;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => seed = parse_seed(args.next().ok_or_else(|| format!("--seed needs a value\n{}", USAGE))?)?,
+            _ => Err(format!("Unrecognised option {}\n{}", arg, USAGE))?
+        }
+    }
+
+    print!("{}", DAYS[day_num - 1].generated_input(day_num, seed).text());
+
+    Ok(())
+}
+
+fn parse_seed(value: &str) -> Result<u64, String> {
+    value.parse().map_err(|_| format!("Invalid seed {}", value))
+}
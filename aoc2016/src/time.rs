@@ -0,0 +1,76 @@
+use aoc::input::Input;
+use aoc2016::{day_nums, Day, DAYS};
+use std::{error::Error, time::{Duration, Instant}};
+
+// Time parsing and each part of days (all by default) on their default inputs
+pub fn time(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut total = Duration::ZERO;
+    let mut failed = 0;
+
+    println!("{:<5} {:>10} {:>10} {:>10} {:>10}", "Day", "Parse", "Part 1", "Part 2", "Total");
+
+    for day_num in day_nums(args)? {
+        match time_day(&DAYS[day_num - 1]) {
+            Ok((parse, parts)) => {
+                // Each part parses for itself, so the parse time is already in the part times
+                let day_total = parts.iter().sum();
+                total += day_total;
+
+                let mut cells: Vec<String> = parts.iter().map(|&t| format_duration(t)).collect();
+                cells.resize(2, "-".to_string());
+
+                println!("{:<5} {:>10} {:>10} {:>10} {:>10}", format!("{:02}", day_num), format_duration(parse), cells[0], cells[1], format_duration(day_total));
+            }
+            Err(e) => {
+                println!("{:<5} FAIL ({})", format!("{:02}", day_num), e);
+                failed += 1;
+            }
+        }
+    }
+
+    println!("{:<5} {:>43}", "Total", format_duration(total));
+
+    if failed > 0 {
+        Err(format!("{} days failed", failed))?
+    }
+
+    Ok(())
+}
+
+// The parse time and the time for each part
+fn time_day(day: &Day) -> Result<(Duration, Vec<Duration>), Box<dyn Error>> {
+    let input = Input::load(&(day.default_input)())?;
+
+    let parse = timed(|| (day.parse)(&input))?;
+    let parts = day.parts().into_iter().map(|part| timed(|| (day.solve)(&input, part).map(|_| ()))).collect::<Result<_, _>>()?;
+
+    Ok((parse, parts))
+}
+
+fn timed<F>(f: F) -> Result<Duration, Box<dyn Error>>
+where F: FnOnce() -> Result<(), Box<dyn Error>> {
+    let start = Instant::now();
+
+    f()?;
+
+    Ok(start.elapsed())
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs_f64();
+
+    if secs < 0.001 {
+        format!("{:.1}µs", secs * 1_000_000.0)
+    } else if secs < 1.0 {
+        format!("{:.2}ms", secs * 1000.0)
+    } else {
+        format!("{:.2}s", secs)
+    }
+}
+
+#[test]
+fn test_format_duration() {
+    assert!(format_duration(Duration::from_nanos(12_340)) == "12.3µs");
+    assert!(format_duration(Duration::from_micros(4_567)) == "4.57ms");
+    assert!(format_duration(Duration::from_millis(12_345)) == "12.35s");
+}
@@ -0,0 +1,94 @@
+use aoc2016::{day_nums, DAYS};
+use aoc::{answer::{parse_answers, same_answer}, input::Input};
+use std::{cmp, error::Error};
+
+// Check days (all by default) against the answers recorded in answersNN.txt
+pub fn verify(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut passed = 0;
+    let mut failed = 0;
+
+    for day_num in day_nums(args)? {
+        match verify_day(day_num) {
+            Ok((day_passed, day_failed)) => {
+                passed += day_passed;
+                failed += day_failed;
+            }
+            Err(e) => {
+                println!("Day {:02}: FAIL ({})", day_num, e);
+                failed += 1;
+            }
+        }
+    }
+
+    println!("{} passed, {} failed", passed, failed);
+
+    if failed > 0 {
+        Err(format!("{} answers failed verification", failed))?
+    }
+
+    Ok(())
+}
+
+fn answers_file(day_num: usize) -> String {
+    format!("answers{:02}.txt", day_num)
+}
+
+// Returns number of parts passed and failed
+fn verify_day(day_num: usize) -> Result<(usize, usize), Box<dyn Error>> {
+    let day = &DAYS[day_num - 1];
+
+    // Load recorded answers
+    let answers = Input::from_file(answers_file(day_num))?;
+    let recorded = parse_answers(answers.text()).map_err(|e| format!("{}: {}", answers.name(), e))?;
+
+    if recorded.is_empty() {
+        Err(format!("{}: no answers recorded", answers.name()))?
+    }
+
+    // Load the input
+    let input = Input::load(&(day.default_input)())?;
+
+    let mut passed = 0;
+    let mut failed = 0;
+
+    for (part, expected) in recorded {
+        match (day.solve)(&input, part) {
+            Ok(actual) if same_answer(&expected, &actual) => {
+                println!("Day {:02} part {}: pass", day_num, part);
+                passed += 1;
+            }
+            Ok(actual) => {
+                println!("Day {:02} part {}: FAIL", day_num, part);
+                print_diff(&expected, &actual);
+                failed += 1;
+            }
+            Err(e) => {
+                println!("Day {:02} part {}: FAIL ({})", day_num, part, e);
+                failed += 1;
+            }
+        }
+    }
+
+    Ok((passed, failed))
+}
+
+// Line by line diff of a mismatched answer
+fn print_diff(expected: &str, actual: &str) {
+    let expected: Vec<&str> = expected.trim_end().lines().collect();
+    let actual: Vec<&str> = actual.trim_end().lines().collect();
+
+    for i in 0..cmp::max(expected.len(), actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(e), Some(a)) if e.trim_end() == a.trim_end() => println!("    {}", e),
+            (e, a) => {
+                if let Some(e) = e {
+                    println!("  - {}", e);
+                }
+
+                if let Some(a) = a {
+                    println!("  + {}", a);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use aoc::{answer::{parse_answers, same_answer, Part}, config::{self, Config}, input::Input};
+use aoc2016::DAYS;
+use std::{error::Error, fs, path::{Path, PathBuf}};
+
+// Puzzle examples live in fixtures/dayNN. NAME.txt is the input, NAME.answers holds the expected
+// answers in the answersNN.txt format and NAME.config, if there is one, has the settings it needs
+
+// Parts too slow for a debug build. cargo test --release checks them too
+const SLOW: [(usize, Part); 3] = [(5, Part::One), (5, Part::Two), (14, Part::Two)];
+
+#[test]
+fn test_fixtures() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../fixtures");
+    let mut failures = Vec::new();
+
+    for day_num in 1..=DAYS.len() {
+        let inputs = fixture_inputs(&root.join(format!("day{:02}", day_num))).unwrap();
+
+        assert!(!inputs.is_empty(), "No fixtures for day {}", day_num);
+
+        for path in inputs {
+            if let Err(e) = check_fixture(day_num, &path, &mut failures) {
+                failures.push(format!("{}: {}", path.display(), e));
+            }
+        }
+    }
+
+    config::set_config(Config::default());
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+// The NAME.txt files in a day's directory
+fn fixture_inputs(dir: &Path) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let mut inputs = Vec::new();
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+
+        if path.extension().is_some_and(|ext| ext == "txt") {
+            inputs.push(path);
+        }
+    }
+
+    inputs.sort();
+
+    Ok(inputs)
+}
+
+fn check_fixture(day_num: usize, path: &Path, failures: &mut Vec<String>) -> Result<(), Box<dyn Error>> {
+    let day = &DAYS[day_num - 1];
+
+    // Settings for this fixture only
+    let mut config = Config::default();
+    let config_path = path.with_extension("config");
+
+    if config_path.exists() {
+        config.load(&config_path)?;
+    }
+
+    config::set_config(config);
+
+    let answers = Input::from_file(path.with_extension("answers"))?;
+    let expected = parse_answers(answers.text()).map_err(|e| format!("{}: {}", answers.name(), e))?;
+
+    if expected.is_empty() {
+        Err(format!("{}: no answers", answers.name()))?
+    }
+
+    let input = Input::from_file(path)?;
+
+    for (part, expected) in expected {
+        if cfg!(debug_assertions) && SLOW.contains(&(day_num, part)) {
+            continue
+        }
+
+        match (day.solve)(&input, part) {
+            Ok(actual) if same_answer(&expected, &actual) => (),
+            Ok(actual) => failures.push(format!("{} part {}: expected {}, got {}", path.display(), part, expected, actual)),
+            Err(e) => failures.push(format!("{} part {}: {}", path.display(), part, e))
+        }
+    }
+
+    Ok(())
+}
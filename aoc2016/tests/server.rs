@@ -0,0 +1,81 @@
+use serde_json::Value;
+use std::{io::{BufRead, BufReader, Read, Write}, net::TcpStream, process::{Child, Command, Stdio}};
+
+// Runs the server binary on a free port and talks to it over HTTP
+
+struct Server {
+    child: Child,
+    port: u16
+}
+
+impl Server {
+    fn start(args: &[&str]) -> Server {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_aoc2016-server"))
+            .args(["--port", "0"]).args(args)
+            .stdout(Stdio::piped())
+            .spawn().unwrap();
+
+        let mut line = String::new();
+        BufReader::new(child.stdout.take().unwrap()).read_line(&mut line).unwrap();
+
+        let port = line.trim().rsplit(':').next().and_then(|p| p.parse().ok()).unwrap_or_else(|| panic!("Unexpected output {}", line));
+
+        Server { child, port }
+    }
+
+    // Returns the status code and JSON body
+    fn post(&self, path: &str, body: &str) -> (u16, Value) {
+        let mut stream = TcpStream::connect(("127.0.0.1", self.port)).unwrap();
+
+        write!(stream, "POST {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", path, body.len(), body).unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        let (head, json) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+
+        (status, serde_json::from_str(json).unwrap())
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[test]
+fn test_solve() {
+    let server = Server::start(&[]);
+
+    let (status, json) = server.post("/day/1", "R5, L5, R5, R3\n");
+    assert!(status == 200);
+    assert!(json["answers"][0]["answer"] == "12");
+    assert!(json["answers"][1]["part"] == 2);
+
+    let (status, json) = server.post("/day/25?part=1", "out a\n");
+    assert!(status == 200 && json["answers"].as_array().unwrap().len() == 1);
+
+    let (status, json) = server.post("/day/3", "1 2 x\n");
+    assert!(status == 422);
+    assert!(json["error"].as_str().unwrap().contains("line 1"));
+
+    assert!(server.post("/day/26", "").0 == 404);
+}
+
+#[test]
+fn test_time_limit() {
+    let server = Server::start(&["--time-limit", "0.2", "--max-running", "1"]);
+
+    // Runs until its step limit, well past the time limit
+    let (status, json) = server.post("/day/12?part=1", "jnz 1 0\n");
+    assert!(status == 200);
+    assert!(json["answers"][0]["error"].as_str().unwrap().contains("Time limit"));
+
+    // The overrun solve has been stopped, so its slot is free again
+    let (status, json) = server.post("/day/1", "R2, L3\n");
+    assert!(status == 200, "{}", json);
+    assert!(json["answers"][0]["answer"] == "5");
+}
@@ -0,0 +1,165 @@
+use crate::{parse_reg, Instruction, MachineInt, Opcode, Program, RegImm};
+use aoc::{input::Input, parse::{self, Token, Words}, solution::Result};
+use std::{collections::{BTreeMap, HashMap}, convert::TryFrom};
+
+// Assembunny source with labels, # comments and blank lines. A line can start with "name:" to label
+// the instruction after it, and jnz and tgl can give a label in place of their offset
+
+// Parse source, turning labels into offsets
+pub fn assemble(source: &str, opcodes: &[Opcode]) -> Result<Program> {
+    let input = Input::from(source);
+    let mut labels = HashMap::new();
+    let mut lines = Vec::new();
+
+    // Find where the labels are and which lines have instructions
+    for (num, line) in input.text().lines().enumerate().map(|(idx, l)| (idx + 1, l)) {
+        let code = line.split('#').next().unwrap_or_default();
+        let labelled = match Words::new(code).next().filter(|t| t.text.ends_with(':')) {
+            Some(token) => {
+                let name = token.strip_suffix(":").map_err(|e| input.parse_error(num, e))?;
+
+                if !is_label(name.text) {
+                    Err(input.parse_error(num, name.error("invalid label")))?
+                }
+
+                if labels.insert(name.text, lines.len()).is_some() {
+                    Err(input.parse_error(num, name.error("duplicate label")))?
+                }
+
+                true
+            }
+            None => false
+        };
+
+        if code.split_whitespace().nth(labelled as usize).is_some() {
+            lines.push((num, code, labelled));
+        }
+    }
+
+    let mut program = Vec::with_capacity(lines.len());
+
+    for (index, (num, code, labelled)) in lines.into_iter().enumerate() {
+        let mut words = Words::new(code);
+
+        if labelled {
+            words.next();
+        }
+
+        let label = |token: Token| -> parse::Result<Option<MachineInt>> {
+            match labels.get(token.text) {
+                Some(&target) => Ok(Some(target as MachineInt - index as MachineInt)),
+                None if is_label(token.text) => Err(token.error("unknown label")),
+                None => Ok(None)
+            }
+        };
+
+        program.push(Instruction::parse(words, opcodes, label).map_err(|e| input.parse_error(num, e))?);
+    }
+
+    Ok(program)
+}
+
+// Letters, digits and underscores, not starting with a digit and not a register name
+fn is_label(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') &&
+        name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') &&
+        parse_reg(name).is_none()
+}
+
+// Turn a program back into source, labelling everywhere a fixed jnz or tgl offset leads
+pub fn disassemble(program: &Program) -> String {
+    let target = |index: usize, instr: &Instruction| match *instr {
+        Instruction::Jnz(_, RegImm::Imm(offset)) | Instruction::Tgl(RegImm::Imm(offset)) => {
+            usize::try_from(index as i64 + offset as i64).ok().filter(|&t| t <= program.len())
+        }
+        _ => None
+    };
+
+    let mut labels: BTreeMap<usize, String> = program.iter().enumerate()
+        .filter_map(|(index, instr)| target(index, instr))
+        .map(|t| (t, String::new()))
+        .collect();
+
+    for (n, name) in labels.values_mut().enumerate() {
+        *name = format!("l{}", n + 1);
+    }
+
+    let mut source = String::new();
+
+    // The end of the program can have a label too
+    for index in 0..=program.len() {
+        if let Some(name) = labels.get(&index) {
+            source += &format!("{}:\n", name);
+        }
+
+        if let Some(instr) = program.get(index) {
+            let label = target(index, instr).map(|t| labels[&t].as_str());
+
+            source += &format!("    {}\n", source_line(instr, label));
+        }
+    }
+
+    source
+}
+
+// An instruction as source, with a label for the jnz or tgl offset if there is one
+fn source_line(instr: &Instruction, label: Option<&str>) -> String {
+    match label {
+        Some(label) => {
+            let mut operands: Vec<String> = instr.operands().iter().map(RegImm::to_string).collect();
+
+            if let Some(offset) = operands.last_mut() {
+                *offset = label.to_string();
+            }
+
+            format!("{} {}", instr.opcode().name(), operands.join(" "))
+        }
+        None => instr.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_instructions;
+
+    #[test]
+    fn test_assemble() {
+        let source = "# Adds b to a\n\
+            \n\
+            loop: inc a\n\
+            \x20   dec b   # counts down\n\
+            \x20   jnz b loop\n\
+            \x20   jnz 1 end\n\
+            \x20   tgl loop\n\
+            end:\n";
+
+        assert!(assemble(source, Opcode::ALL).unwrap() == parse_instructions("inc a\ndec b\njnz b -2\njnz 1 2\ntgl -4\n", Opcode::ALL).unwrap());
+
+        // Errors give the line and column
+        let error = |source| assemble(source, Opcode::ALL).unwrap_err().to_string();
+
+        assert!(error("inc a\njnz a nowhere\n").contains("line 2, column 7: unknown label 'nowhere'"));
+        assert!(error("x: inc a\nx: dec a\n").contains("line 2, column 1: duplicate label 'x'"));
+        assert!(error("b: inc a\n").contains("invalid label 'b'"));
+        assert!(error("  x: blah a\n").contains("line 1, column 6: unrecognised instruction"));
+
+        // Without tgl, offsets still have to be numbers or labels
+        assert!(assemble("x: jnz 1 x\n", &[Opcode::Jnz]).unwrap() == vec![Instruction::Jnz(RegImm::Imm(1), RegImm::Imm(0))]);
+        assert!(assemble("jnz 1 a\n", &[Opcode::Jnz]).is_err());
+    }
+
+    #[test]
+    fn test_disassemble() {
+        let program = parse_instructions("cpy 2 a\ninc b\ndec a\njnz a -2\ntgl 2\njnz 1 c\njnz 1 99\n", Opcode::ALL).unwrap();
+        let source = disassemble(&program);
+
+        assert!(source == "    cpy 2 a\nl1:\n    inc b\n    dec a\n    jnz a l1\n    tgl l2\n    jnz 1 c\nl2:\n    jnz 1 99\n");
+        assert!(assemble(&source, Opcode::ALL).unwrap() == program);
+
+        // A jump to the end
+        let program = parse_instructions("jnz a 2\ninc a\n", Opcode::ALL).unwrap();
+        assert!(disassemble(&program) == "    jnz a l1\n    inc a\nl1:\n");
+        assert!(assemble(&disassemble(&program), Opcode::ALL).unwrap() == program);
+    }
+}
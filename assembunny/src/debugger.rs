@@ -0,0 +1,390 @@
+use crate::{format_program, parse_reg, step, MachineInt, Program, State};
+use aoc::solution::Result;
+use std::{fmt, fs, io::Write};
+
+// An interactive debugger. Each command line is carried out one instruction at a time, so
+// breakpoints and watches see every change, and says what happened
+
+pub const HELP: &str = "Commands:
+  step [N]              (s)  Run N instructions, default 1
+  continue              (c)  Run until a breakpoint or watch stops it, or the program halts
+  break [PC | REG OP N] (b)  Stop at an instruction, or when a register comparison (== != < <= > >=) becomes true.
+                             With no argument, list the breakpoints
+  delete N              (d)  Remove breakpoint N
+  watch REG             (w)  Stop when a register changes
+  unwatch REG                Stop watching a register
+  set REG|pc N               Change a register or the program counter
+  regs                  (r)  Show the registers and the next instruction
+  list                  (l)  Show the program as it is now, marking instructions changed by tgl
+  dump PATH                  Save the program as it is now, which can be run or debugged again
+  reset                      Start again with the original program and registers
+  help                  (h)  Show this
+  quit                  (q)  Leave the debugger";
+
+// continue gives up after this many instructions without stopping
+const CONTINUE_LIMIT: u64 = 1_000_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Compare {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge
+}
+
+impl Compare {
+    fn parse(op: &str) -> Option<Compare> {
+        match op {
+            "==" => Some(Compare::Eq),
+            "!=" => Some(Compare::Ne),
+            "<" => Some(Compare::Lt),
+            "<=" => Some(Compare::Le),
+            ">" => Some(Compare::Gt),
+            ">=" => Some(Compare::Ge),
+            _ => None
+        }
+    }
+
+    fn test(&self, value: MachineInt, n: MachineInt) -> bool {
+        match self {
+            Compare::Eq => value == n,
+            Compare::Ne => value != n,
+            Compare::Lt => value < n,
+            Compare::Le => value <= n,
+            Compare::Gt => value > n,
+            Compare::Ge => value >= n
+        }
+    }
+
+    fn symbol(&self) -> &'static str {
+        match self {
+            Compare::Eq => "==",
+            Compare::Ne => "!=",
+            Compare::Lt => "<",
+            Compare::Le => "<=",
+            Compare::Gt => ">",
+            Compare::Ge => ">="
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Breakpoint {
+    Pc(MachineInt),
+    Reg(u8, Compare, MachineInt)
+}
+
+impl Breakpoint {
+    // "PC" or "REG OP N", with or without spaces
+    fn parse(text: &str) -> Result<Breakpoint> {
+        if let Ok(pc) = text.parse() {
+            return Ok(Breakpoint::Pc(pc))
+        }
+
+        let invalid = || format!("Invalid breakpoint {} (expecting PC or REG OP N)", text);
+
+        let op_start = text.find(|c| "=!<>".contains(c)).ok_or_else(invalid)?;
+        let op_end = text[op_start..].find(|c| !"=!<>".contains(c)).map_or(text.len(), |len| op_start + len);
+
+        let reg = parse_register(text[..op_start].trim())?;
+        let compare = Compare::parse(&text[op_start..op_end]).ok_or_else(invalid)?;
+        let n = text[op_end..].trim().parse().map_err(|_| invalid())?;
+
+        Ok(Breakpoint::Reg(reg, compare, n))
+    }
+
+    // A pc breakpoint stops whenever the instruction is reached, a comparison only when it becomes true
+    fn hit(&self, before: &State, after: &State) -> bool {
+        match *self {
+            Breakpoint::Pc(pc) => after.pc == pc,
+            Breakpoint::Reg(r, compare, n) => !compare.test(before.reg[r as usize], n) && compare.test(after.reg[r as usize], n)
+        }
+    }
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Breakpoint::Pc(pc) => write!(f, "pc {}", pc),
+            Breakpoint::Reg(r, compare, n) => write!(f, "{} {} {}", reg_name(*r), compare.symbol(), n)
+        }
+    }
+}
+
+pub struct Debugger {
+    start: State,
+    start_program: Program,
+    state: State,
+    program: Program,
+    breakpoints: Vec<Breakpoint>,
+    watches: Vec<u8>,
+    steps: u64
+}
+
+impl Debugger {
+    pub fn new(state: State, program: Program) -> Debugger {
+        Debugger {
+            start: state.clone(),
+            start_program: program.clone(),
+            state,
+            program,
+            breakpoints: Vec::new(),
+            watches: Vec::new(),
+            steps: 0
+        }
+    }
+
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+
+    pub fn program(&self) -> &Program {
+        &self.program
+    }
+
+    // Carry out a command line, writing what happened to out. Returns false for quit
+    pub fn command<W: Write>(&mut self, line: &str, out: &mut W) -> Result<bool> {
+        let mut words = line.split_whitespace();
+
+        let command = match words.next() {
+            Some(command) => command,
+            None => return Ok(true)
+        };
+
+        let args: Vec<&str> = words.collect();
+
+        match (command, &args[..]) {
+            ("s" | "step", []) => self.run(1, out)?,
+            ("s" | "step", [n]) => self.run(n.parse().map_err(|_| format!("Invalid number of steps {}", n))?, out)?,
+            ("c" | "continue", []) => self.run(CONTINUE_LIMIT, out)?,
+            ("b" | "break", []) => {
+                if self.breakpoints.is_empty() {
+                    writeln!(out, "No breakpoints")?;
+                }
+
+                for (i, breakpoint) in self.breakpoints.iter().enumerate() {
+                    writeln!(out, "Breakpoint {}: {}", i + 1, breakpoint)?;
+                }
+            }
+            ("b" | "break", _) => {
+                let breakpoint = Breakpoint::parse(&args.join(" "))?;
+
+                self.breakpoints.push(breakpoint);
+                writeln!(out, "Breakpoint {}: {}", self.breakpoints.len(), breakpoint)?;
+            }
+            ("d" | "delete", [n]) => {
+                let i = n.parse::<usize>().ok().filter(|&i| i >= 1 && i <= self.breakpoints.len())
+                    .ok_or_else(|| format!("No breakpoint {}", n))?;
+
+                self.breakpoints.remove(i - 1);
+            }
+            ("w" | "watch", [reg]) => {
+                let r = parse_register(reg)?;
+
+                if !self.watches.contains(&r) {
+                    self.watches.push(r);
+                }
+            }
+            ("unwatch", [reg]) => {
+                let r = parse_register(reg)?;
+
+                self.watches.retain(|&w| w != r);
+            }
+            ("set", [target, n]) => {
+                let n = n.parse().map_err(|_| format!("Invalid value {}", n))?;
+
+                match *target {
+                    "pc" => self.state.pc = n,
+                    _ => self.state.reg[parse_register(target)? as usize] = n
+                }
+
+                self.show_state(out)?;
+            }
+            ("r" | "regs", []) => self.show_state(out)?,
+            ("l" | "list", []) => self.list(out)?,
+            ("dump", [path]) => {
+                fs::write(path, format_program(&self.program)).map_err(|e| format!("Can't write {}: {}", path, e))?;
+                writeln!(out, "Saved {} instructions to {}", self.program.len(), path)?;
+            }
+            ("reset", []) => {
+                self.state = self.start.clone();
+                self.program = self.start_program.clone();
+                self.steps = 0;
+
+                self.show_state(out)?;
+            }
+            ("q" | "quit", []) => return Ok(false),
+            ("h" | "help", []) => writeln!(out, "{}", HELP)?,
+            _ => Err(format!("Unrecognised command {} (try help)", line.trim()))?
+        }
+
+        Ok(true)
+    }
+
+    fn running(&self) -> bool {
+        self.state.pc >= 0 && (self.state.pc as usize) < self.program.len()
+    }
+
+    // Run up to max_steps instructions, stopping early for breakpoints and watches
+    fn run<W: Write>(&mut self, max_steps: u64, out: &mut W) -> Result<()> {
+        let mut steps = 0;
+        let mut stopped = false;
+
+        while self.running() && steps < max_steps && !stopped {
+            let before = self.state.clone();
+
+            if let Some(value) = step(&mut self.state, &mut self.program) {
+                writeln!(out, "out {}", value)?;
+            }
+
+            steps += 1;
+            self.steps += 1;
+
+            for &r in &self.watches {
+                let (old, new) = (before.reg[r as usize], self.state.reg[r as usize]);
+
+                if old != new {
+                    writeln!(out, "Watch {}: {} -> {}", reg_name(r), old, new)?;
+                    stopped = true;
+                }
+            }
+
+            for (i, breakpoint) in self.breakpoints.iter().enumerate() {
+                if breakpoint.hit(&before, &self.state) {
+                    writeln!(out, "Breakpoint {}: {}", i + 1, breakpoint)?;
+                    stopped = true;
+                }
+            }
+        }
+
+        if !self.running() {
+            writeln!(out, "Program halted after {} instructions", self.steps)?;
+        } else if !stopped && max_steps == CONTINUE_LIMIT {
+            writeln!(out, "Still running after {} more instructions", steps)?;
+        }
+
+        self.show_state(out)
+    }
+
+    fn show_state<W: Write>(&self, out: &mut W) -> Result<()> {
+        let regs: Vec<String> = self.state.reg.iter().enumerate().map(|(r, value)| format!("{} {}", reg_name(r as u8), value)).collect();
+
+        writeln!(out, "pc {}  {}  ({} instructions run)", self.state.pc, regs.join("  "), self.steps)?;
+
+        if self.running() {
+            self.list_line(self.state.pc as usize, out)?;
+        }
+
+        Ok(())
+    }
+
+    fn list<W: Write>(&self, out: &mut W) -> Result<()> {
+        for i in 0..self.program.len() {
+            self.list_line(i, out)?;
+        }
+
+        Ok(())
+    }
+
+    // One instruction, marked => if it's next, * if it has a breakpoint and noting if tgl has changed it
+    fn list_line<W: Write>(&self, i: usize, out: &mut W) -> Result<()> {
+        let next = if self.state.pc == i as MachineInt { "=>" } else { "" };
+        let breakpoint = if self.breakpoints.contains(&Breakpoint::Pc(i as MachineInt)) { "*" } else { "" };
+
+        write!(out, "{:>2}{:>1} {:>4}  {}", next, breakpoint, i, self.program[i])?;
+
+        if self.program[i] != self.start_program[i] {
+            write!(out, "  (toggled from {})", self.start_program[i])?;
+        }
+
+        writeln!(out)?;
+
+        Ok(())
+    }
+}
+
+fn parse_register(name: &str) -> Result<u8> {
+    Ok(parse_reg(name).ok_or_else(|| format!("Invalid register {}", name))?)
+}
+
+fn reg_name(r: u8) -> char {
+    (r + b'a') as char
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_instructions, Opcode};
+
+    fn debugger(source: &str) -> Debugger {
+        Debugger::new(State::default(), parse_instructions(source, Opcode::ALL).unwrap())
+    }
+
+    fn command(debugger: &mut Debugger, line: &str) -> String {
+        let mut out = Vec::new();
+
+        debugger.command(line, &mut out).unwrap();
+
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_breakpoints() {
+        let mut debugger = debugger("cpy 3 a\nout a\ndec a\njnz a -2\n");
+
+        assert!(command(&mut debugger, "step 2").contains("out 3"));
+        assert!(debugger.state().pc == 2);
+
+        command(&mut debugger, "break a < 2");
+        command(&mut debugger, "b 3");
+
+        // The pc breakpoint, then the comparison once a gets down to 1
+        assert!(command(&mut debugger, "c").contains("Breakpoint 2: pc 3"));
+        assert!(command(&mut debugger, "c").contains("Breakpoint 1: a < 2"));
+        assert!(debugger.state().reg[0] == 1);
+
+        command(&mut debugger, "delete 1");
+        command(&mut debugger, "delete 1");
+        assert!(command(&mut debugger, "continue").contains("Program halted after 10 instructions"));
+
+        command(&mut debugger, "reset");
+        assert!(debugger.state() == &State::default());
+
+        // Bad commands leave things as they were
+        for line in ["break a <> 2", "break e == 1", "delete 1", "step x", "set pc", "jump 3"] {
+            assert!(debugger.command(line, &mut Vec::new()).is_err());
+        }
+
+        assert!(!debugger.command("quit", &mut Vec::new()).unwrap());
+    }
+
+    #[test]
+    fn test_watch() {
+        let mut debugger = debugger("cpy 2 a\ntgl a\ninc b\ninc b\nout b\n");
+
+        command(&mut debugger, "watch b");
+        command(&mut debugger, "set b 5");
+
+        let out = command(&mut debugger, "continue");
+        assert!(out.contains("Watch b: 5 -> 6"));
+        assert!(debugger.state().pc == 3);
+
+        // tgl turned the second inc b into dec b
+        assert!(command(&mut debugger, "list").contains("3  dec b  (toggled from inc b)"));
+
+        command(&mut debugger, "unwatch b");
+        assert!(command(&mut debugger, "c").contains("out 5"));
+
+        // The toggled program can be saved and read back
+        let path = std::env::temp_dir().join(format!("assembunny-dump-{}.txt", std::process::id()));
+        command(&mut debugger, &format!("dump {}", path.display()));
+
+        let text = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert!(text == "cpy 2 a\ntgl a\ninc b\ndec b\nout b\n");
+        assert!(&parse_instructions(&text, Opcode::ALL).unwrap() == debugger.program());
+    }
+}
@@ -0,0 +1,386 @@
+use aoc::{input::Input, parse::{self, Token, Words}, solution::Result};
+use optimise::Optimiser;
+use std::fmt;
+
+pub mod assembler;
+pub mod debugger;
+pub mod optimise;
+
+// The assembunny machine from days 12, 23 and 25. Each day says which opcodes its programs may use
+
+pub type MachineInt = i32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Opcode {
+    Cpy,
+    Inc,
+    Dec,
+    Jnz,
+    Tgl,
+    Out
+}
+
+impl Opcode {
+    pub const ALL: &'static [Opcode] = &[Opcode::Cpy, Opcode::Inc, Opcode::Dec, Opcode::Jnz, Opcode::Tgl, Opcode::Out];
+
+    fn parse(name: &str) -> Option<Opcode> {
+        match name {
+            "cpy" => Some(Opcode::Cpy),
+            "inc" => Some(Opcode::Inc),
+            "dec" => Some(Opcode::Dec),
+            "jnz" => Some(Opcode::Jnz),
+            "tgl" => Some(Opcode::Tgl),
+            "out" => Some(Opcode::Out),
+            _ => None
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Opcode::Cpy => "cpy",
+            Opcode::Inc => "inc",
+            Opcode::Dec => "dec",
+            Opcode::Jnz => "jnz",
+            Opcode::Tgl => "tgl",
+            Opcode::Out => "out"
+        }
+    }
+}
+
+// Four registers, a to d, and the program counter
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct State {
+    pub reg: [MachineInt; 4],
+    pub pc: MachineInt
+}
+
+// tgl can turn instructions into ones with an immediate where a register should be. Those are skipped
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Instruction {
+    Cpy(RegImm, RegImm),
+    Inc(RegImm),
+    Dec(RegImm),
+    Jnz(RegImm, RegImm),
+    Tgl(RegImm),
+    Out(RegImm)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum RegImm {
+    Reg(u8),
+    Imm(MachineInt)
+}
+
+impl RegImm {
+    fn parse(token: Token) -> parse::Result<RegImm> {
+        match parse_reg(token.text) {
+            Some(r) => Ok(RegImm::Reg(r)),
+            None => token.parse("register or value").map(RegImm::Imm)
+        }
+    }
+
+    fn parse_reg(token: Token) -> parse::Result<RegImm> {
+        parse_reg(token.text).map(RegImm::Reg).ok_or_else(|| token.error("invalid register"))
+    }
+
+    pub fn get(&self, state: &State) -> MachineInt {
+        match self {
+            RegImm::Reg(r) => state.reg[*r as usize],
+            RegImm::Imm(i) => *i
+        }
+    }
+}
+
+// As it is written in a program
+impl fmt::Display for RegImm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegImm::Reg(r) => write!(f, "{}", (*r + b'a') as char),
+            RegImm::Imm(i) => write!(f, "{}", *i)
+        }
+    }
+}
+
+impl fmt::Debug for RegImm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+pub type Program = Vec<Instruction>;
+
+pub fn parse_reg(string: &str) -> Option<u8> {
+    match string {
+        "a" => Some(0),
+        "b" => Some(1),
+        "c" => Some(2),
+        "d" => Some(3),
+        _ => None
+    }
+}
+
+// A program as parse_instructions reads it, one instruction per line
+pub fn format_program(program: &[Instruction]) -> String {
+    program.iter().map(|instr| format!("{}\n", instr)).collect()
+}
+
+// Parse a program which only uses the given opcodes
+pub fn parse_instructions(input: &str, opcodes: &[Opcode]) -> Result<Program> {
+    Ok(Input::from(input).parse_lines(|l| Instruction::parse(Words::new(l), opcodes, |_| Ok(None)))?)
+}
+
+impl Instruction {
+    // label gives the offset for a jnz or tgl target which is a label, or None if it isn't one
+    fn parse<F>(mut words: Words, opcodes: &[Opcode], label: F) -> parse::Result<Instruction>
+    where F: Fn(Token) -> parse::Result<Option<MachineInt>> {
+        let instr = words.word("instruction")?;
+        let opcode = Opcode::parse(instr.text).filter(|op| opcodes.contains(op)).ok_or_else(|| instr.error("unrecognised instruction"))?;
+
+        // Without tgl nothing can change an instruction, so operands which must be registers are checked here
+        let fixed = !opcodes.contains(&Opcode::Tgl);
+        let reg = |token| if fixed { RegImm::parse_reg(token) } else { RegImm::parse(token) };
+        let offset = |token: Token| match label(token)? {
+            Some(offset) => Ok(RegImm::Imm(offset)),
+            None if fixed => token.parse("offset").map(RegImm::Imm),
+            None => RegImm::parse(token)
+        };
+
+        let instr = match opcode {
+            Opcode::Cpy => Instruction::Cpy(RegImm::parse(words.word("source")?)?, reg(words.word("register")?)?),
+            Opcode::Inc => Instruction::Inc(reg(words.word("register")?)?),
+            Opcode::Dec => Instruction::Dec(reg(words.word("register")?)?),
+            Opcode::Jnz => Instruction::Jnz(RegImm::parse(words.word("condition")?)?, offset(words.word("offset")?)?),
+            Opcode::Tgl => Instruction::Tgl(offset(words.word("offset")?)?),
+            Opcode::Out => Instruction::Out(RegImm::parse(words.word("value")?)?)
+        };
+
+        words.end()?;
+
+        Ok(instr)
+    }
+
+    pub fn opcode(&self) -> Opcode {
+        match self {
+            Instruction::Cpy(..) => Opcode::Cpy,
+            Instruction::Inc(_) => Opcode::Inc,
+            Instruction::Dec(_) => Opcode::Dec,
+            Instruction::Jnz(..) => Opcode::Jnz,
+            Instruction::Tgl(_) => Opcode::Tgl,
+            Instruction::Out(_) => Opcode::Out
+        }
+    }
+
+    pub fn operands(&self) -> Vec<RegImm> {
+        match *self {
+            Instruction::Cpy(ri1, ri2) | Instruction::Jnz(ri1, ri2) => vec![ri1, ri2],
+            Instruction::Inc(ri) | Instruction::Dec(ri) | Instruction::Tgl(ri) | Instruction::Out(ri) => vec![ri]
+        }
+    }
+
+    // What tgl turns this instruction into
+    fn toggled(&self) -> Instruction {
+        match *self {
+            Instruction::Cpy(ri1, ri2) => Instruction::Jnz(ri1, ri2),
+            Instruction::Inc(ri) => Instruction::Dec(ri),
+            Instruction::Dec(ri) | Instruction::Tgl(ri) | Instruction::Out(ri) => Instruction::Inc(ri),
+            Instruction::Jnz(ri1, ri2) => Instruction::Cpy(ri1, ri2)
+        }
+    }
+}
+
+// The instruction as it is written in a program, which parses back to the same instruction
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.opcode().name())?;
+
+        for operand in self.operands() {
+            write!(f, " {}", operand)?;
+        }
+
+        Ok(())
+    }
+}
+
+// Execute the instruction at pc, which must be in the program. Returns the value of an out instruction
+pub fn step(state: &mut State, program: &mut Program) -> Option<MachineInt> {
+    let mut output = None;
+
+    match &program[state.pc as usize] {
+        Instruction::Cpy(ri1, ri2) => {
+            if let RegImm::Reg(r) = ri2 {
+                state.reg[*r as usize] = ri1.get(state);
+            }
+        }
+        Instruction::Inc(ri) => {
+            if let RegImm::Reg(r) = ri {
+                state.reg[*r as usize] = state.reg[*r as usize].wrapping_add(1);
+            }
+        }
+        Instruction::Dec(ri) => {
+            if let RegImm::Reg(r) = ri {
+                state.reg[*r as usize] = state.reg[*r as usize].wrapping_sub(1);
+            }
+        }
+        Instruction::Jnz(ri1, ri2) => {
+            if ri1.get(state) != 0 {
+                state.pc = state.pc.saturating_add(ri2.get(state)).saturating_sub(1);
+            }
+        }
+        Instruction::Tgl(ri) => {
+            let target = state.pc.saturating_add(ri.get(state));
+
+            if target >= 0 && (target as usize) < program.len() {
+                program[target as usize] = program[target as usize].toggled();
+            }
+        }
+        Instruction::Out(ri) => output = Some(ri.get(state))
+    }
+
+    state.pc = state.pc.saturating_add(1);
+
+    output
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    // pc left the program
+    Halted,
+    // The output function asked to stop
+    Output,
+    StepLimit
+}
+
+// Run until the program halts, output returns false or the instructions in budget have run, taking those run off it.
+// Add and multiply loops are run in one go and count as one time round, so the budget bounds the time taken
+pub fn run<F>(state: &mut State, program: &mut Program, budget: &mut u64, mut output: F) -> Stop
+where F: FnMut(MachineInt) -> bool {
+    let mut optimiser = Optimiser::new(program);
+
+    while state.pc >= 0 && (state.pc as usize) < program.len() {
+        if *budget == 0 {
+            return Stop::StepLimit
+        }
+
+        if let Some(n) = optimiser.run_fused(state) {
+            *budget = budget.saturating_sub(n);
+            continue
+        }
+
+        *budget -= 1;
+
+        if let Some(value) = optimiser.step(state, program) {
+            if !output(value) {
+                return Stop::Output
+            }
+        }
+    }
+
+    Stop::Halted
+}
+
+// Run a program which should halt within max_steps instructions, ignoring any output
+pub fn execute(state: &mut State, program: &mut Program, max_steps: u64) -> Result<()> {
+    let mut budget = max_steps;
+
+    match run(state, program, &mut budget, |_| true) {
+        Stop::StepLimit => Err(format!("Program still running after {} instructions", max_steps))?,
+        _ => Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::rng::Rng;
+
+    const BASIC: &[Opcode] = &[Opcode::Cpy, Opcode::Inc, Opcode::Dec, Opcode::Jnz];
+    const TOGGLE: &[Opcode] = &[Opcode::Cpy, Opcode::Inc, Opcode::Dec, Opcode::Jnz, Opcode::Tgl];
+
+    #[test]
+    fn test_parse() {
+        assert!(parse_instructions("cpy 41 a\njnz a 2\n", BASIC).unwrap() == vec![
+            Instruction::Cpy(RegImm::Imm(41), RegImm::Reg(0)),
+            Instruction::Jnz(RegImm::Reg(0), RegImm::Imm(2))
+        ]);
+
+        // Opcodes the day doesn't use
+        assert!(parse_instructions("tgl a\n", BASIC).unwrap_err().to_string().contains("unrecognised instruction"));
+        assert!(parse_instructions("out a\n", Opcode::ALL).is_ok());
+
+        // Registers are only needed when nothing can toggle the instruction
+        assert!(parse_instructions("cpy 1 2\n", BASIC).unwrap_err().to_string().contains("invalid register"));
+        assert!(parse_instructions("jnz 1 a\n", BASIC).is_err());
+        assert!(parse_instructions("cpy 1 2\njnz 1 a\n", Opcode::ALL).is_ok());
+    }
+
+    #[test]
+    fn test_run() {
+        let mut program = parse_instructions("cpy 3 a\nout a\ndec a\njnz a -2\n", Opcode::ALL).unwrap();
+        let mut state = State::default();
+        let mut outputs = Vec::new();
+
+        let mut budget = 100;
+
+        assert!(run(&mut state, &mut program.clone(), &mut budget, |v| { outputs.push(v); true }) == Stop::Halted);
+        assert!(outputs == vec![3, 2, 1]);
+        assert!(budget == 90);
+
+        assert!(run(&mut State::default(), &mut program.clone(), &mut 100, |v| v != 2) == Stop::Output);
+        assert!(run(&mut State::default(), &mut program, &mut 5, |_| true) == Stop::StepLimit);
+        assert!(execute(&mut State::default(), &mut parse_instructions("jnz 1 0\n", Opcode::ALL).unwrap(), 10).is_err());
+
+        // Jumps as far back as possible from the start
+        assert!(run(&mut State::default(), &mut parse_instructions("jnz 1 -2147483648\n", Opcode::ALL).unwrap(), &mut 10, |_| true) == Stop::Halted);
+    }
+
+    // Any instruction parse_instructions would give with these opcodes
+    fn random_instruction(rng: &mut Rng, opcodes: &[Opcode]) -> Instruction {
+        let fixed = !opcodes.contains(&Opcode::Tgl);
+
+        let reg = |rng: &mut Rng| RegImm::Reg(rng.below(4) as u8);
+        let imm = |rng: &mut Rng| RegImm::Imm(match rng.below(3) {
+            0 => *rng.choose(&[0, 1, -1, MachineInt::MIN, MachineInt::MAX]),
+            1 => rng.range(0..=100) as MachineInt - 50,
+            _ => rng.next_u64() as MachineInt
+        });
+        let reg_imm = |rng: &mut Rng| if rng.chance(1, 2) { reg(rng) } else { imm(rng) };
+
+        // Toggled instructions can have operands of either kind
+        let dest = |rng: &mut Rng| if fixed { reg(rng) } else { reg_imm(rng) };
+        let offset = |rng: &mut Rng| if fixed { imm(rng) } else { reg_imm(rng) };
+
+        match *rng.choose(opcodes) {
+            Opcode::Cpy => Instruction::Cpy(reg_imm(rng), dest(rng)),
+            Opcode::Inc => Instruction::Inc(dest(rng)),
+            Opcode::Dec => Instruction::Dec(dest(rng)),
+            Opcode::Jnz => Instruction::Jnz(reg_imm(rng), offset(rng)),
+            Opcode::Tgl => Instruction::Tgl(reg_imm(rng)),
+            Opcode::Out => Instruction::Out(reg_imm(rng))
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut rng = Rng::new(2016);
+
+        for opcodes in [BASIC, TOGGLE, Opcode::ALL] {
+            for _ in 0..500 {
+                let len = rng.range(1..=20) as usize;
+                let program: Program = (0..len).map(|_| random_instruction(&mut rng, opcodes)).collect();
+
+                let text = format_program(&program);
+                let parsed = parse_instructions(&text, opcodes).unwrap();
+
+                assert!(parsed == program, "{}", text);
+                assert!(format_program(&parsed) == text);
+            }
+        }
+
+        // A program tgl has changed can be written out and read back
+        let mut program = parse_instructions("cpy 2 a\ntgl a\ntgl a\ntgl a\ncpy 1 a\ndec a\ndec a\n", TOGGLE).unwrap();
+        execute(&mut State::default(), &mut program, 100).unwrap();
+
+        assert!(format_program(&program) == "cpy 2 a\ntgl a\ntgl a\ninc a\njnz 1 a\ndec a\ndec a\n");
+        assert!(parse_instructions(&format_program(&program), TOGGLE).unwrap() == program);
+    }
+}
@@ -0,0 +1,83 @@
+use aoc::input::Input;
+use assembunny::{assembler::{assemble, disassemble}, debugger::Debugger, parse_reg, Opcode, State};
+use std::{env, error::Error, io::{self, Write}};
+
+const USAGE: &str = "Usage: assembunny debug <PATH> [--reg REG=VALUE]...
+       assembunny disasm <PATH>
+
+Programs can use labels, # comments and blank lines";
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.first().map(|a| a.as_str()) {
+        Some("debug") => debug(&args[1..]),
+        Some("disasm") => disasm(&args[1..]),
+        _ => Err(USAGE)?
+    }
+}
+
+// Step through a program, which can use every opcode. Commands are read from stdin
+fn debug(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut args = args.iter();
+
+    let input = Input::from_file(args.next().ok_or(USAGE)?)?;
+    let program = assemble(input.text(), Opcode::ALL).map_err(|e| format!("{}: {}", input.name(), e))?;
+
+    let mut state = State::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--reg" => {
+                let value = args.next().ok_or_else(|| format!("--reg needs a value\n{}", USAGE))?;
+                let invalid = || format!("Invalid register setting {} (expecting REG=VALUE)", value);
+
+                let (reg, n) = value.split_once('=').ok_or_else(invalid)?;
+
+                state.reg[parse_reg(reg).ok_or_else(invalid)? as usize] = n.parse().map_err(|_| invalid())?;
+            }
+            _ => Err(format!("Unrecognised option {}\n{}", arg, USAGE))?
+        }
+    }
+
+    let mut debugger = Debugger::new(state, program);
+    let mut stdout = io::stdout();
+
+    println!("{}: {} instructions. Type help for the commands", input.name(), debugger.program().len());
+    debugger.command("regs", &mut stdout)?;
+
+    loop {
+        print!("(bunny) ");
+        stdout.flush()?;
+
+        let mut line = String::new();
+
+        if io::stdin().read_line(&mut line)? == 0 {
+            println!();
+            break
+        }
+
+        match debugger.command(&line, &mut stdout) {
+            Ok(true) => (),
+            Ok(false) => break,
+            Err(e) => println!("{}", e)
+        }
+    }
+
+    Ok(())
+}
+
+// Print a program with labels in place of jnz and tgl offsets
+fn disasm(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let path = match args {
+        [path] => path,
+        _ => Err(USAGE)?
+    };
+
+    let input = Input::from_file(path)?;
+    let program = assemble(input.text(), Opcode::ALL).map_err(|e| format!("{}: {}", input.name(), e))?;
+
+    print!("{}", disassemble(&program));
+
+    Ok(())
+}
@@ -0,0 +1,207 @@
+use crate::{step, Instruction, MachineInt, Program, RegImm, State};
+use std::convert::TryFrom;
+
+// Loops of inc, dec and jnz which only add or multiply are run in one go. tgl can rewrite the
+// instructions a loop is made of, so loops near a toggled instruction are dropped and looked for again
+
+// Instructions in the longest loop
+const MAX_LEN: usize = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fused {
+    // inc to / dec from / jnz from -2, either way round: to += from, from = 0
+    Add { to: u8, from: u8 },
+    // cpy by count / an add loop from count to to / dec times / jnz times -5: to += by * times, count = times = 0
+    Mul { to: u8, by: RegImm, count: u8, times: u8 }
+}
+
+impl Fused {
+    // Look for a loop starting at index
+    fn find(program: &[Instruction], index: usize) -> Option<Fused> {
+        let code = program.get(index..)?;
+
+        Fused::find_mul(code).or_else(|| Fused::find_add(code))
+    }
+
+    fn find_add(code: &[Instruction]) -> Option<Fused> {
+        use Instruction::{Dec, Inc, Jnz};
+        use RegImm::{Imm, Reg};
+
+        match *code.get(..3)? {
+            [Inc(Reg(to)), Dec(Reg(from)), Jnz(Reg(cond), Imm(-2))] |
+            [Dec(Reg(from)), Inc(Reg(to)), Jnz(Reg(cond), Imm(-2))] if cond == from && to != from => Some(Fused::Add { to, from }),
+            _ => None
+        }
+    }
+
+    fn find_mul(code: &[Instruction]) -> Option<Fused> {
+        use Instruction::{Cpy, Dec, Jnz};
+        use RegImm::{Imm, Reg};
+
+        match *code.get(..MAX_LEN)? {
+            [Cpy(by, Reg(count)), _, _, _, Dec(Reg(times)), Jnz(Reg(cond), Imm(-5))] if cond == times => {
+                match Fused::find_add(&code[1..4])? {
+                    // by has to stay the same and the three registers have to be different
+                    Fused::Add { to, from } if from == count && times != to && times != count &&
+                        !matches!(by, Reg(r) if r == to || r == count || r == times) => {
+                        Some(Fused::Mul { to, by, count, times })
+                    }
+                    _ => None
+                }
+            }
+            _ => None
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Fused::Add { .. } => 3,
+            Fused::Mul { .. } => MAX_LEN
+        }
+    }
+
+    // Run the whole loop. Returns the number of instructions in it.
+    // Loops which wrap around through negative numbers are left to run one instruction at a time
+    fn apply(&self, state: &mut State) -> Option<u64> {
+        match *self {
+            Fused::Add { to, from } => {
+                let n = state.reg[from as usize];
+
+                if n <= 0 {
+                    return None
+                }
+
+                state.reg[to as usize] = state.reg[to as usize].wrapping_add(n);
+                state.reg[from as usize] = 0;
+            }
+            Fused::Mul { to, by, count, times } => {
+                let (n, m) = (by.get(state), state.reg[times as usize]);
+
+                if n <= 0 || m <= 0 {
+                    return None
+                }
+
+                state.reg[to as usize] = state.reg[to as usize].wrapping_add(n.wrapping_mul(m));
+                state.reg[count as usize] = 0;
+                state.reg[times as usize] = 0;
+            }
+        }
+
+        state.pc += self.len() as MachineInt;
+
+        Some(self.len() as u64)
+    }
+}
+
+// The loop, if any, starting at each instruction of a program
+pub struct Optimiser {
+    fused: Vec<Option<Fused>>
+}
+
+impl Optimiser {
+    pub fn new(program: &Program) -> Optimiser {
+        Optimiser { fused: (0..program.len()).map(|i| Fused::find(program, i)).collect() }
+    }
+
+    pub fn fused(&self, pc: MachineInt) -> Option<Fused> {
+        usize::try_from(pc).ok().and_then(|pc| self.fused.get(pc).copied().flatten())
+    }
+
+    // Run a loop starting at pc if there is one. Returns the number of instructions in the loop
+    pub fn run_fused(&self, state: &mut State) -> Option<u64> {
+        self.fused(state.pc)?.apply(state)
+    }
+
+    // Execute one instruction like step, looking for loops again around any instruction tgl changes
+    pub fn step(&mut self, state: &mut State, program: &mut Program) -> Option<MachineInt> {
+        let toggled = match program[state.pc as usize] {
+            Instruction::Tgl(ri) => usize::try_from(state.pc.saturating_add(ri.get(state))).ok().filter(|&i| i < program.len()),
+            _ => None
+        };
+
+        let output = step(state, program);
+
+        if let Some(index) = toggled {
+            self.invalidate(program, index);
+        }
+
+        output
+    }
+
+    // The instruction at index has changed, so check every loop which could include it
+    fn invalidate(&mut self, program: &Program, index: usize) {
+        for start in index.saturating_sub(MAX_LEN - 1)..=index {
+            self.fused[start] = Fused::find(program, start);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_instructions, run, Opcode, Stop};
+
+    const OPCODES: &[Opcode] = &[Opcode::Cpy, Opcode::Inc, Opcode::Dec, Opcode::Jnz, Opcode::Tgl];
+
+    #[test]
+    fn test_find() {
+        let program = parse_instructions("cpy b c\ninc a\ndec c\njnz c -2\ndec d\njnz d -5\ndec b\ninc a\njnz b -2\n", OPCODES).unwrap();
+        let optimiser = Optimiser::new(&program);
+
+        assert!(optimiser.fused(0) == Some(Fused::Mul { to: 0, by: RegImm::Reg(1), count: 2, times: 3 }));
+        assert!(optimiser.fused(1) == Some(Fused::Add { to: 0, from: 2 }));
+        assert!(optimiser.fused(6) == Some(Fused::Add { to: 0, from: 1 }));
+        assert!(optimiser.fused(2).is_none());
+        assert!(optimiser.fused(-1).is_none());
+
+        // Loops which wouldn't stop or where the count changes as it goes
+        for source in ["inc a\ndec a\njnz a -2\n", "inc a\ndec b\njnz a -2\n", "cpy a c\ninc a\ndec c\njnz c -2\ndec d\njnz d -5\n"] {
+            assert!(Optimiser::new(&parse_instructions(source, OPCODES).unwrap()).fused(0).is_none());
+        }
+    }
+
+    #[test]
+    fn test_toggle() {
+        let mut program = parse_instructions("inc a\ndec b\njnz b -2\ntgl c\n", OPCODES).unwrap();
+        let mut optimiser = Optimiser::new(&program);
+        let mut state = State { reg: [0, 0, -3, 0], pc: 3 };
+
+        // inc a becomes dec a, which is no longer an add
+        optimiser.step(&mut state, &mut program);
+        assert!(optimiser.fused(0).is_none());
+
+        // And back again
+        state.pc = 3;
+        optimiser.step(&mut state, &mut program);
+        assert!(optimiser.fused(0) == Some(Fused::Add { to: 0, from: 1 }));
+    }
+
+    #[test]
+    fn test_same_as_step() {
+        // A factorial which toggles its tail into a multiply
+        let mut program = parse_instructions("cpy a b\ndec b\ncpy a d\ncpy 0 a\ncpy b c\ninc a\ndec c\njnz c -2\ndec d\njnz d -5\n\
+            dec b\ncpy b c\ncpy c d\ndec d\ninc c\njnz d -2\ntgl c\ncpy -16 c\njnz 1 c\n\
+            cpy 7 c\njnz 9 d\ninc a\ninc d\njnz d -2\ninc c\njnz c -5\n", OPCODES).unwrap();
+
+        let start = State { reg: [6, 0, 0, 0], pc: 0 };
+
+        // One instruction at a time
+        let mut slow = start.clone();
+        let mut slow_program = program.clone();
+        let mut steps = 0;
+
+        while slow.pc >= 0 && (slow.pc as usize) < slow_program.len() {
+            step(&mut slow, &mut slow_program);
+            steps += 1;
+        }
+
+        assert!(slow.reg[0] == 720 + 63 && steps == 5513);
+
+        // Each loop only counts once round, so far fewer steps are needed
+        assert!(run(&mut start.clone(), &mut program.clone(), &mut 71, |_| true) == Stop::StepLimit);
+
+        let mut fast = start;
+        assert!(run(&mut fast, &mut program, &mut 72, |_| true) == Stop::Halted);
+        assert!(fast == slow && program == slow_program);
+    }
+}
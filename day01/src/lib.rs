@@ -0,0 +1,102 @@
+use aoc::{input::{Input, Source}, parse::{self, Token, Words}, rng::Rng, solution::{Result, Solution}};
+use grid::{Coord, Dir};
+use std::collections::HashSet;
+
+pub fn default_input() -> Source {
+    Source::file("input01.txt")
+}
+
+// A few hundred directions including a square, so somewhere is always visited twice
+pub fn generate(rng: &mut Rng) -> String {
+    let mut directions: Vec<String> = (0..rng.range(100..=300)).map(|_| {
+        format!("{}{}", rng.choose(&['L', 'R']), rng.range(1..=9))
+    }).collect();
+
+    let turn = rng.choose(&['L', 'R']);
+    let length = rng.range(1..=9);
+    let at = rng.below(directions.len());
+
+    directions.splice(at..at, (0..4).map(|_| format!("{}{}", turn, length)));
+
+    directions.join(", ") + "\n"
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn part1(&self, input: &str) -> Result<usize> {
+        Ok(walk(input)?.0.manhattan())
+    }
+
+    fn part2(&self, input: &str) -> Result<usize> {
+        Ok(walk(input)?.1.ok_or("No location visited twice")?.manhattan())
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        parse_directions(&Input::from(input))?;
+
+        Ok(())
+    }
+}
+
+// Follow the directions, returning the final location and the first location visited twice
+fn walk(input: &str) -> Result<(Coord, Option<Coord>)> {
+    let directions = parse_directions(&Input::from(input))?;
+
+    let mut dir = Dir::Up;
+    let mut pos = Coord::ORIGIN;
+    let mut visited: HashSet<Coord> = HashSet::new();
+    let mut first_double: Option<Coord> = None;
+
+    visited.insert(pos);
+
+    for (turn, length) in directions {
+        dir = match turn {
+            'L' => dir.turn_left(),
+            'R' => dir.turn_right(),
+            _ => panic!("Invalid turn {}", turn)
+        };
+
+        for _ in 0..length {
+            pos = pos.step(dir);
+
+            if !visited.insert(pos) && first_double.is_none() {
+                first_double = Some(pos)
+            }
+        }
+    }
+
+    Ok((pos, first_double))
+}
+
+type Direction = (char, u8);
+
+fn parse_directions(input: &Input) -> Result<Vec<Direction>> {
+    let directions = input.parse_single_line(|line| {
+        Words::new(line).map(|word| {
+            let word = Token { text: word.text.trim_end_matches(','), ..word };
+
+            let turn = match word.text.chars().next() {
+                Some(c @ ('L' | 'R')) => c,
+                _ => Err(word.error("invalid direction"))?
+            };
+
+            let length = word.strip_prefix(&turn.to_string())?.parse::<u8>("length")?;
+
+            Ok((turn, length))
+        }).collect::<parse::Result<Vec<Direction>>>()
+    })?;
+
+    Ok(directions)
+}
+
+#[test]
+fn test_examples() {
+    assert!(Day01.part1("R2, L3").unwrap() == 5);
+    assert!(Day01.part1("R2, R2, R2").unwrap() == 2);
+    assert!(Day01.part1("R5, L5, R5, R3").unwrap() == 12);
+    assert!(Day01.part2("R8, R4, R4, R8").unwrap() == 4);
+}
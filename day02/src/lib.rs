@@ -0,0 +1,113 @@
+use aoc::{input::{Input, Source}, rng::Rng, solution::{Result, Solution}};
+use std::cmp::{max, min};
+
+pub fn default_input() -> Source {
+    Source::file("input02.txt")
+}
+
+// Lines of moves, one per key
+pub fn generate(rng: &mut Rng) -> String {
+    (0..rng.range(3..=8)).map(|_| {
+        (0..rng.range(100..=600)).map(|_| *rng.choose(&['U', 'D', 'L', 'R'])).collect::<String>() + "\n"
+    }).collect()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Part1 = String;
+    type Part2 = String;
+
+    fn part1(&self, input: &str) -> Result<String> {
+        Ok(part1(&parse_directions(input)?))
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        Ok(part2(&parse_directions(input)?))
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        parse_directions(input)?;
+
+        Ok(())
+    }
+}
+
+fn parse_directions(input: &str) -> Result<Vec<Vec<char>>> {
+    let directions = Input::from(input).grid(|c| match c {
+        'U' | 'D' | 'L' | 'R' => Some(c),
+        _ => None
+    })?;
+
+    Ok(directions)
+}
+
+const KEYS1: [[char; 3]; 3] = [
+    ['1', '2', '3'],
+    ['4', '5', '6'],
+    ['7', '8', '9']
+];
+
+fn part1(directions: &[Vec<char>]) -> String {
+    let mut key_presses: Vec<char> = Vec::new();
+    let mut x: i8 = 1;
+    let mut y: i8 = 1;
+
+    for keypress in directions {
+        for dir in keypress {
+            match dir {
+                'U' => y = max(0, y - 1),
+                'D' => y = min(2, y + 1),
+                'L' => x = max(0, x - 1),
+                'R' => x = min(2, x + 1),
+                _ => panic!("Unrecognised direction {}", dir)
+            }
+        }
+
+        key_presses.push(KEYS1[y as usize][x as usize]);
+    }
+
+    key_presses.iter().collect()
+}
+
+const KEYS2: [[char; 5]; 5] = [
+    [' ', ' ', '1', ' ', ' '],
+    [' ', '2', '3', '4', ' '],
+    ['5', '6', '7', '8', '9'],
+    [' ', 'A', 'B', 'C', ' '],
+    [' ', ' ', 'D', ' ', ' '],
+];
+
+fn part2(directions: &[Vec<char>]) -> String {
+    let mut key_presses: Vec<char> = Vec::new();
+    // Start on 5
+    let mut x: i8 = 0;
+    let mut y: i8 = 2;
+
+    for keypress in directions {
+        for dir in keypress {
+            let (newx, newy) = match dir {
+                'U' => (x, y - 1),
+                'D' => (x, y + 1),
+                'L' => (x - 1, y),
+                'R' => (x + 1, y),
+                _ => panic!("Unrecognised direction {}", dir)
+            };
+
+            if !(0..=4).contains(&newx) || !(0..=4).contains(&newy) {
+                continue
+            }
+
+            if KEYS2[newy as usize][newx as usize] == ' ' {
+                continue
+            }
+
+            x = newx;
+            y = newy;
+        }
+
+        key_presses.push(KEYS2[y as usize][x as usize]);
+    }
+
+    key_presses.iter().collect()
+}
@@ -0,0 +1,101 @@
+use aoc::{input::{Input, Source}, parse::{self, Words}, rng::Rng, solution::{Result, Solution}};
+
+pub fn default_input() -> Source {
+    Source::file("input03.txt")
+}
+
+// Rows of three sides in columns, a multiple of three rows for part 2
+pub fn generate(rng: &mut Rng) -> String {
+    (0..rng.range(100..=600) * 3).map(|_| {
+        format!("{:>5}{:>5}{:>5}\n", rng.range(1..=999), rng.range(1..=999), rng.range(1..=999))
+    }).collect()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Part1 = u16;
+    type Part2 = u16;
+
+    fn part1(&self, input: &str) -> Result<u16> {
+        Ok(part1(&parse_triangles(input)?))
+    }
+
+    fn part2(&self, input: &str) -> Result<u16> {
+        let triangles = parse_triangles(input)?;
+
+        if triangles.len() % 3 != 0 {
+            Err(format!("{} triangles is not a multiple of 3", triangles.len()))?
+        }
+
+        Ok(part2(&triangles))
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        parse_triangles(input)?;
+
+        Ok(())
+    }
+}
+
+fn parse_triangles(input: &str) -> Result<Vec<Vec<u16>>> {
+    let triangles = Input::from(input).parse_lines(|l| -> parse::Result<Vec<u16>> {
+        let mut words = Words::new(l);
+
+        let sides = vec![words.parse("side")?, words.parse("side")?, words.parse("side")?];
+
+        words.end()?;
+
+        Ok(sides)
+    })?;
+
+    Ok(triangles)
+}
+
+fn part1(triangles: &[Vec<u16>]) -> u16 {
+    let mut valid: u16 = 0;
+
+    for t in triangles {
+        if triangle_valid(t[0], t[1], t[2]) {
+            valid += 1;
+        }
+    }
+
+    valid
+}
+
+fn part2(triangles: &[Vec<u16>]) -> u16 {
+    let mut valid: u16 = 0;
+    let mut ti = triangles.iter();
+
+    loop {
+        let t1 = ti.next();
+        if t1.is_none() {
+            break
+        }
+
+        let t1 = t1.unwrap();
+        let t2 = ti.next().unwrap();
+        let t3 = ti.next().unwrap();
+
+        if triangle_valid(t1[0], t2[0], t3[0]) {
+            valid += 1;
+        }
+
+        if triangle_valid(t1[1], t2[1], t3[1]) {
+            valid += 1;
+        }
+
+        if triangle_valid(t1[2], t2[2], t3[2]) {
+            valid += 1;
+        }
+    }
+
+    valid
+}
+
+fn triangle_valid(s1: u16, s2: u16, s3: u16) -> bool {
+    let (s1, s2, s3) = (s1 as u32, s2 as u32, s3 as u32);
+
+    s1 + s2 > s3 && s1 + s3 > s2 && s2 + s3 > s1
+}
@@ -0,0 +1,175 @@
+use aoc::{config, input::{Input, Source}, parse::{self, LineError, Token}, rng::Rng, solution::{Result, Solution}};
+use log::trace;
+use std::{cmp::Ordering, collections::HashMap};
+
+pub fn default_input() -> Source {
+    Source::file("input04.txt")
+}
+
+const STORAGE: &str = "northpole object storage";
+
+// Real and decoy rooms, with the storage room somewhere among them
+pub fn generate(rng: &mut Rng) -> String {
+    let mut rooms: Vec<String> = (0..rng.range(200..=1000)).map(|_| {
+        let name = loop {
+            let name = (0..rng.range(1..=5)).map(|_| {
+                let length = rng.range(2..=10) as usize;
+                rng.letters(length)
+            }).collect::<Vec<_>>().join("-");
+
+            // Checksums are five letters
+            if checksum(&name).len() == 5 {
+                break name
+            }
+        };
+
+        let checksum = if rng.chance(2, 3) {
+            checksum(&name)
+        } else {
+            rng.letters(5)
+        };
+
+        format!("{}-{}[{}]", name, rng.range(100..=999), checksum)
+    }).collect();
+
+    // Encrypt the storage room name
+    let sector = rng.range(100..=999) as u16;
+
+    let name: String = STORAGE.chars().map(|c| {
+        if c == ' ' {
+            '-'
+        } else {
+            (((c as u16 - 'a' as u16 + 26 - sector % 26) % 26) as u8 + b'a') as char
+        }
+    }).collect();
+
+    let at = rng.below(rooms.len());
+    rooms.insert(at, format!("{}-{}[{}]", name, sector, checksum(&name)));
+
+    rooms.iter().map(|r| format!("{}\n", r)).collect()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Part1 = u32;
+    type Part2 = u16;
+
+    fn part1(&self, input: &str) -> Result<u32> {
+        Ok(part1(parse_rooms(input)?).0)
+    }
+
+    fn part2(&self, input: &str) -> Result<u16> {
+        let (_, rooms) = part1(parse_rooms(input)?);
+        let storage = config::get("day04.storage", STORAGE.to_string())?;
+
+        Ok(part2(rooms, &storage).ok_or_else(|| format!("No room is named {}", storage))?)
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        parse_rooms(input)?;
+
+        Ok(())
+    }
+}
+
+fn parse_rooms(input: &str) -> Result<Vec<RoomId>> {
+    Ok(Input::from(input).parse_lines(RoomId::parse)?)
+}
+
+fn part1(rooms: Vec<RoomId>) -> (u32, Vec<RoomId>) {
+    let mut sector_sum: u32 = 0;
+    let mut valid_rooms = Vec::new();
+
+    for r in rooms {
+        if r.checksum == checksum(&r.room) {
+            sector_sum += r.sector as u32;
+            valid_rooms.push(r);
+        }
+    }
+
+    (sector_sum, valid_rooms)
+}
+
+// The five most common letters, ties in alphabetical order
+fn checksum(room: &str) -> String {
+    let mut charmap: HashMap<char, usize> = HashMap::new();
+
+    // Count chars
+    for c in room.chars() {
+        if c != '-' {
+            if let Some(count) = charmap.get_mut(&c) {
+                *count += 1;
+            } else {
+                charmap.insert(c, 1);
+            }
+        }
+    }
+
+    // Build vector from hashmap
+    let mut charvec: Vec<(&char, &usize)> = charmap.iter().collect();
+
+    // Sort by char occurrence descending then char
+    charvec.sort_by(|&(&c1, &occ1), (&c2, &occ2)| {
+        let cmp1 = occ2.cmp(&occ1);
+
+        if cmp1 == Ordering::Equal {
+            c1.cmp(&c2)
+        } else {
+            cmp1
+        }
+    });
+
+    // Build expected checksum
+    charvec.iter().take(5).map(|&(&c, _)| c).collect()
+}
+
+// Sector of the room with the given decrypted name
+fn part2(rooms: Vec<RoomId>, storage: &str) -> Option<u16> {
+    let mut answer = None;
+
+    for r in rooms {
+        let decrypted: String = r.room.chars().map(|c| {
+            if c == '-' {
+                ' '
+            } else {
+                let mut letter = c as u16 - 'a' as u16;
+                letter = (letter + r.sector % 26) % 26;
+                (letter as u8 + b'a') as char
+            }
+        }).collect();
+
+        trace!("{} = {}", decrypted, r.sector);
+
+        if decrypted == storage {
+            answer = Some(r.sector)
+        }
+    }
+
+    answer
+}
+
+struct RoomId {
+    room: String,
+    sector: u16,
+    checksum: String
+}
+
+impl RoomId {
+    fn parse(line: &str) -> parse::Result<RoomId> {
+        let line = Token::line(line);
+
+        let (room, rest) = line.rsplit_once('-').ok_or_else(|| line.error("no sector in room"))?;
+        let (sector, checksum) = rest.strip_suffix("]")?.rsplit_once('[').ok_or_else(|| rest.error("no checksum in"))?;
+
+        if let Some((idx, c)) = room.text.char_indices().find(|&(_, c)| c != '-' && !c.is_ascii_lowercase()) {
+            Err(LineError::at(room.column + idx, &c.to_string(), "invalid character in room name"))?
+        }
+
+        Ok(RoomId {
+            room: room.text.to_string(),
+            sector: sector.parse("sector")?,
+            checksum: checksum.text.to_string()
+        })
+    }
+}
@@ -0,0 +1,103 @@
+use aoc::{config, input::{Input, Source}, rng::Rng, solution::{Result, Solution}};
+use log::debug;
+use md5::Digest;
+
+const KEY: &str = "reyedfim";
+
+pub fn default_input() -> Source {
+    config::input("day05.input", KEY)
+}
+
+// A door ID like the real ones
+pub fn generate(rng: &mut Rng) -> String {
+    rng.letters(8) + "\n"
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Part1 = String;
+    type Part2 = String;
+
+    fn part1(&self, input: &str) -> Result<String> {
+        Ok(part1(&Input::from(input).single_line()?))
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        Ok(part2(&Input::from(input).single_line()?))
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        Input::from(input).single_line()?;
+
+        Ok(())
+    }
+}
+
+fn part1(key: &str) -> String {
+    let mut chars = Vec::new();
+
+    for n in 0.. {
+        let digest = md5::compute(format!("{}{}", key, n));
+
+        if check5(&digest) {
+            let charnum = digest[2] & 0x0f;
+
+            let c = if charnum < 0xa {
+                (charnum + b'0') as char
+            } else {
+                ((charnum - 10) + b'a') as char
+            };
+
+            chars.push(c);
+            debug!("Password character {} from index {} is {}", chars.len(), n, c);
+
+            if chars.len() == 8 {
+                break
+            }
+        }
+    }
+
+    chars.iter().collect()
+}
+
+fn part2(key: &str) -> String {
+    let mut chars: [char; 8] = [' '; 8];
+    let mut found = 0;
+
+    for n in 0.. {
+        let digest = md5::compute(format!("{}{}", key, n));
+
+        if check5(&digest) {
+            let charpos = digest[2] & 0x0f;
+
+            if charpos > 7 || chars[charpos as usize] != ' ' {
+                continue
+            }
+
+            let charnum = (digest[3] & 0xf0) >> 4;
+
+            let c = if charnum < 0xa {
+                (charnum + b'0') as char
+            } else {
+                ((charnum - 10) + b'a') as char
+            };
+
+            chars[charpos as usize] = c;
+            debug!("Password character {} from index {} is {}", charpos + 1, n, c);
+
+            found += 1;
+
+            if found == 8 {
+                break
+            }
+        }
+    }
+
+    chars.iter().collect()
+}
+
+#[inline]
+fn check5(digest: &Digest) -> bool{
+    digest[0] == 0 && digest[1] == 0 && digest[2] & 0xf0 == 0
+}
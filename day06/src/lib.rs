@@ -0,0 +1,82 @@
+use aoc::{input::{Input, Source}, parse::Token, rng::Rng, solution::{Result, Solution}};
+use std::collections::HashMap;
+
+pub fn default_input() -> Source {
+    Source::file("input06.txt")
+}
+
+// Noisy copies of a message, all the same length
+pub fn generate(rng: &mut Rng) -> String {
+    let length = rng.range(4..=12) as usize;
+    let message = rng.letters(length);
+
+    (0..rng.range(100..=600)).map(|_| {
+        message.chars().map(|c| if rng.chance(1, 2) { c } else { rng.letter() }).collect::<String>() + "\n"
+    }).collect()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Part1 = String;
+    type Part2 = String;
+
+    fn part1(&self, input: &str) -> Result<String> {
+        Ok(decode(input)?.0)
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        Ok(decode(input)?.1)
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        decode(input)?;
+
+        Ok(())
+    }
+}
+
+// Decode the messages using the most and least common characters
+fn decode(input: &str) -> Result<(String, String)> {
+    let input = Input::from(input);
+    let length = input.single_line()?.chars().count();
+
+    // Messages must all be the same length
+    let strings = input.parse_lines(|l| {
+        if l.chars().count() == length {
+            Ok(l.to_string())
+        } else {
+            Err(Token::line(l).error(&format!("expected {} characters in message", length)))
+        }
+    })?;
+
+    // Create a hashmap for each char pos
+    let mut char_occs: Vec<HashMap<char, usize>> = vec![HashMap::new(); length];
+
+    // Count character occs for each string
+    for s in strings {
+        for (idx, c) in s.chars().enumerate() {
+            if let Some(ent) = char_occs[idx].get_mut(&c) {
+                *ent += 1;
+            } else {
+                char_occs[idx].insert(c, 1);
+            }
+        }
+    }
+
+    // Build message 1
+    let message1: String = char_occs.iter().map(|hm| {
+        let mut occ_vec: Vec<(&char, &usize)> = hm.iter().collect();
+        occ_vec.sort_by_key(|&(_, &cnt)| std::cmp::Reverse(cnt));
+        occ_vec[0].0
+    }).collect();
+
+    // Build message 2
+    let message2: String = char_occs.iter().map(|hm| {
+        let mut occ_vec: Vec<(&char, &usize)> = hm.iter().collect();
+        occ_vec.sort_by_key(|&(_, &cnt)| cnt);
+        occ_vec[0].0
+    }).collect();
+
+    Ok((message1, message2))
+}
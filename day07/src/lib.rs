@@ -0,0 +1,145 @@
+use aoc::{input::{Input, Source}, rng::Rng, solution::{Result, Solution}};
+
+pub fn default_input() -> Source {
+    Source::file("input07.txt")
+}
+
+// Addresses of alternating outside and bracketed sequences, some with ABBAs and ABAs planted
+pub fn generate(rng: &mut Rng) -> String {
+    (0..rng.range(500..=2000)).map(|_| {
+        let sequences = rng.range(1..=3) as usize * 2 + 1;
+
+        let mut address = String::new();
+
+        for seq in 0..sequences {
+            let length = rng.range(4..=16) as usize;
+            let mut letters = rng.letters(length);
+
+            if rng.chance(1, 5) {
+                let (a, b) = (rng.letter(), rng.letter());
+                let at = rng.below(letters.len());
+
+                let pattern = if rng.chance(1, 2) { format!("{}{}{}{}", a, b, b, a) } else { format!("{}{}{}", a, b, a) };
+                letters.insert_str(at, &pattern);
+            }
+
+            if seq % 2 == 1 {
+                address += &format!("[{}]", letters);
+            } else {
+                address += &letters;
+            }
+        }
+
+        address + "\n"
+    }).collect()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Part1 = u16;
+    type Part2 = u16;
+
+    fn part1(&self, input: &str) -> Result<u16> {
+        Ok(count_supported(input).0)
+    }
+
+    fn part2(&self, input: &str) -> Result<u16> {
+        Ok(count_supported(input).1)
+    }
+}
+
+// Count addresses supporting TLS and SSL
+fn count_supported(input: &str) -> (u16, u16) {
+    let addresses = Input::from(input).lines();
+
+    let mut tls = 0;
+    let mut ssl = 0;
+
+    for a in addresses {
+        let elems: Vec<&str> = a.split(|c| {
+            matches!(c, '[' | ']')
+        }).collect();
+    
+        if address_supports_tls(&elems) {
+            tls += 1;
+        }
+
+        if address_supports_ssl(&elems) {
+            ssl += 1;
+        }
+    }
+
+    (tls, ssl)
+}
+
+fn address_supports_tls(elems: &[&str]) -> bool {
+    let mut result = false;
+
+    for (idx, e) in elems.iter().enumerate() {
+        if idx % 2 == 0 {
+            // Outside brackets
+            if contains_abba(e) {
+                result = true;
+            }
+        } else {
+            // Inside brackets
+            if contains_abba(e) {
+                return false;
+            }
+        }
+    }
+
+    result
+}
+
+fn address_supports_ssl(elems: &[&str]) -> bool {
+    let mut result = false;
+
+    let mut abas = Vec::new();
+
+    // Loop strings outside brackets
+    for e in elems.iter().step_by(2) {
+        contains_aba(e, &mut abas);
+    }
+
+    if !abas.is_empty() {
+        // Loop strings outside brackets
+        for e in elems.iter().skip(1).step_by(2) {
+            for aba in &abas {
+                if contains_bab(e, aba.1, aba.0) {
+                    result = true;
+                    break
+                }
+            }
+
+            if result {
+                break
+            }
+        }
+    }
+
+    result
+}
+
+fn contains_abba(string: &str) -> bool {
+    let chars: Vec<char> = string.chars().collect();
+
+    chars.windows(4).any(|w| w[0] == w[3] && w[0] != w[1] && w[1] == w[2])
+}
+
+fn contains_aba(string: &str, abas: &mut Vec<(char, char)>) {
+    let chars: Vec<char> = string.chars().collect();
+
+    for w in chars.windows(3) {
+        if w[0] == w[2] && w[0] != w[1] {
+            abas.push((w[0], w[1]))
+        }
+    }
+}
+
+fn contains_bab(string: &str, c1: char, c2: char) -> bool {
+    let chars: Vec<char> = string.chars().collect();
+
+    chars.windows(3).any(|w| w[0] == c1 && w[1] == c2 && w[2] == c1)
+}
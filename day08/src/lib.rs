@@ -0,0 +1,227 @@
+use aoc::{config, input::{Input, Source}, parse::{self, Token, Words}, rng::Rng, solution::{Result, Solution}};
+use grid::{Coord, Grid};
+use render::{Animation, Canvas, Draw, BLACK, WHITE};
+
+pub fn default_input() -> Source {
+    Source::file("input08.txt")
+}
+
+// Rects and rotations which fit the screen
+pub fn generate(rng: &mut Rng) -> String {
+    (0..rng.range(100..=200)).map(|_| match rng.below(3) {
+        0 => format!("rect {}x{}\n", rng.range(1..=8), rng.range(1..=ROWS as u64)),
+        1 => format!("rotate row y={} by {}\n", rng.below(ROWS), rng.range(1..=COLS as u64 - 1)),
+        _ => format!("rotate column x={} by {}\n", rng.below(COLS), rng.range(1..=ROWS as u64 - 1))
+    }).collect()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Part1 = u16;
+    type Part2 = String;
+
+    fn part1(&self, input: &str) -> Result<u16> {
+        let screen = screen()?;
+
+        Ok(count_lit(&process(&parse_commands(input, screen)?, screen)?))
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let screen = screen()?;
+
+        Ok(board_string(&process(&parse_commands(input, screen)?, screen)?))
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        parse_commands(input, screen()?)?;
+
+        Ok(())
+    }
+}
+
+const ROWS: usize = 6;
+const COLS: usize = 50;
+
+const SCALE: u16 = 8;
+const FRAME_DELAY: u16 = 3;
+
+// Lit pixels are true
+type Board = Grid<bool>;
+
+#[derive(Clone, Copy)]
+struct Screen {
+    cols: usize,
+    rows: usize
+}
+
+// The screen size can be set in the config. Positions have to fit a u8
+fn screen() -> Result<Screen> {
+    let screen = Screen {
+        cols: config::get("day08.cols", COLS)?,
+        rows: config::get("day08.rows", ROWS)?
+    };
+
+    if !(1..=255).contains(&screen.cols) || !(1..=255).contains(&screen.rows) {
+        Err(format!("Screen size {}x{} must be from 1x1 to 255x255", screen.cols, screen.rows))?
+    }
+
+    Ok(screen)
+}
+
+fn process(commands: &[Command], screen: Screen) -> Result<Board> {
+    let mut board = Board::new(screen.cols, screen.rows, false);
+
+    // Start animation
+    let mut animation = Animation::start("", || blank(&board), &[BLACK, WHITE], FRAME_DELAY)?;
+
+    // Draw frame
+    draw_board(&board, &mut animation);
+
+    // Process commands
+    for cmd in commands {
+        match cmd {
+            Command::Rect(w, h) => rect(&mut board, &mut animation, *w, *h),
+            Command::RotRow(y, shift) => rot_row_by(&mut board, &mut animation, *y, *shift),
+            Command::RotCol(x, shift) => rot_col_by(&mut board, &mut animation, *x, *shift)
+        }
+    }
+
+    if let Some(animation) = animation {
+        animation.finish()?;
+    }
+
+    // Picture of the final screen
+    render::still("", || blank(&board), &[BLACK, WHITE], |draw| draw_lit(&board, draw))?;
+
+    Ok(board)
+}
+
+fn rect(board: &mut Board, animation: &mut Option<Animation>, w: u8, h: u8) {
+    for y in 0..h {
+        board.row_mut(y as usize)[..w as usize].fill(true);
+    }
+
+    // Draw frame
+    draw_board(board, animation);
+}
+
+fn rot_row_by(board: &mut Board, animation: &mut Option<Animation>, y: u8, shift: u8) {
+    for _ in 0..shift {
+        board.row_mut(y as usize).rotate_right(1);
+
+        // Draw frame
+        draw_board(board, animation);
+    }
+}
+
+fn rot_col_by(board: &mut Board, animation: &mut Option<Animation>, x: u8, shift: u8) {
+    for _ in 0..shift {
+        rot_col(board, x);
+
+        // Draw frame
+        draw_board(board, animation);
+    }
+}
+
+fn rot_col(board: &mut Board, x: u8) {
+    let at = |y: usize| Coord::new(x as isize, y as isize);
+
+    let save = board[at(board.height() - 1)];
+
+    for y in (1..board.height()).rev() {
+        board[at(y)] = board[at(y - 1)];
+    }
+
+    board[at(0)] = save;
+}
+
+fn count_lit(board: &Board) -> u16 {
+    board.iter().filter(|(_, &lit)| lit).count() as u16
+}
+
+fn board_string(board: &Board) -> String {
+    board.render(|&lit| if lit { '#' } else { ' ' })
+}
+
+fn blank(board: &Board) -> Result<Canvas> {
+    Canvas::scaled(board.width() as u16, board.height() as u16, SCALE)
+}
+
+// Lit pixels in white on black
+fn draw_lit(board: &Board, draw: &mut dyn Draw) {
+    for (pos, &lit) in board.iter() {
+        if lit {
+            draw.cell(pos.x as usize, pos.y as usize, 1);
+        }
+    }
+}
+
+fn draw_board(board: &Board, animation: &mut Option<Animation>) {
+    if let Some(animation) = animation {
+        animation.frame(|draw| draw_lit(board, draw));
+    }
+}
+
+#[derive(Debug)]
+enum Command {
+    Rect(u8, u8),
+    RotRow(u8, u8),
+    RotCol(u8, u8)
+}
+
+fn parse_commands(input: &str, screen: Screen) -> Result<Vec<Command>> {
+    Ok(Input::from(input).parse_lines(|l| Command::parse(l, screen))?)
+}
+
+impl Command {
+    fn parse(line: &str, screen: Screen) -> parse::Result<Command> {
+        let mut words = Words::new(line);
+
+        let command = words.word("command")?;
+
+        let command = match command.text {
+            "rect" => {
+                let dims = words.word("dimensions")?;
+
+                match dims.split('x')[..] {
+                    [w, h] => Command::Rect(board_num(w, "width", screen.cols)?, board_num(h, "height", screen.rows)?),
+                    _ => Err(dims.error("expected WxH, found"))?
+                }
+            }
+            "rotate" => {
+                let axis = words.word("row or column")?;
+
+                match axis.text {
+                    "row" => {
+                        let y = board_num(words.word("row")?.strip_prefix("y=")?, "row", screen.rows - 1)?;
+                        words.keyword("by")?;
+                        Command::RotRow(y, words.parse("shift")?)
+                    }
+                    "column" => {
+                        let x = board_num(words.word("column")?.strip_prefix("x=")?, "column", screen.cols - 1)?;
+                        words.keyword("by")?;
+                        Command::RotCol(x, words.parse("shift")?)
+                    }
+                    _ => Err(axis.error("expected row or column, found"))?
+                }
+            }
+            _ => Err(command.error("unrecognised command"))?
+        };
+
+        words.end()?;
+
+        Ok(command)
+    }
+}
+
+// Parse a board size or position no bigger than max
+fn board_num(token: Token, what: &str, max: usize) -> parse::Result<u8> {
+    let num = token.parse::<u8>(what)?;
+
+    if num as usize > max {
+        Err(token.error(&format!("{} out of range", what)))?
+    }
+
+    Ok(num)
+}
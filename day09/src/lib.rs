@@ -0,0 +1,143 @@
+use aoc::{input::{Input, Source}, parse::{self, LineError}, rng::Rng, solution::{Result, Solution}};
+
+pub fn default_input() -> Source {
+    Source::file("input09.txt")
+}
+
+// Letters and nested markers, each marker covering whole sections so part 2 can expand them
+pub fn generate(rng: &mut Rng) -> String {
+    let length = rng.range(5000..=15000) as usize;
+    let mut text = String::new();
+
+    while text.len() < length {
+        text += &section(rng, 3);
+    }
+
+    text + "\n"
+}
+
+fn section(rng: &mut Rng, depth: u8) -> String {
+    if depth == 0 || rng.chance(1, 3) {
+        let length = rng.range(1..=20) as usize;
+
+        rng.letters(length).to_uppercase()
+    } else {
+        let data: String = (0..rng.range(1..=4)).map(|_| section(rng, depth - 1)).collect();
+
+        format!("({}x{}){}", data.len(), rng.range(2..=15), data)
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn part1(&self, input: &str) -> Result<usize> {
+        Ok(Input::from(input).parse_single_line(|l| uncompressed_len(l, 1))?)
+    }
+
+    fn part2(&self, input: &str) -> Result<usize> {
+        Ok(Input::from(input).parse_single_line(|l| uncompressed_len(l, 2))?)
+    }
+
+    // Part 2 checks the markers inside repeated sections too
+    fn parse(&self, input: &str) -> Result<()> {
+        self.part2(input)?;
+
+        Ok(())
+    }
+}
+
+fn uncompressed_len(compressed: &str, part: u8) -> parse::Result<usize> {
+    let compressed_chars: Vec<char> = compressed.chars().collect();
+
+    uncompressed_section_len(&compressed_chars, part, 0, compressed_chars.len())
+}
+
+fn uncompressed_section_len(compressed_chars: &[char], part: u8, start: usize, end: usize) -> parse::Result<usize> {
+    let mut uncompressed_chars = 0;
+
+    let mut i = start;
+
+    while i < end {
+        let c1 = compressed_chars[i];
+
+        match c1 {
+            '(' => {
+                let (repchars, repeats, next) = get_compression_details(compressed_chars, i, end)?;
+
+                let section_len = if part == 1 {
+                    repchars
+                } else {
+                    uncompressed_section_len(compressed_chars, part, next, next + repchars)?
+                };
+
+                uncompressed_chars = repeats.checked_mul(section_len).and_then(|len| len.checked_add(uncompressed_chars))
+                    .ok_or_else(|| LineError::at(i + 1, "(", "decompressed length too large for marker at"))?;
+
+                i = next + repchars;
+            }
+            _ => {
+                uncompressed_chars += 1;
+                i += 1;
+            }
+        }
+    }
+
+    Ok(uncompressed_chars)
+}
+
+// Parse the (AxB) marker at start, returning the characters, repeats and position after the marker
+fn get_compression_details(compressed_chars: &[char], start: usize, limit: usize) -> parse::Result<(usize, usize, usize)> {
+    let end = compressed_chars[start..limit].iter().position(|c| *c == ')')
+        .map(|p| start + p)
+        .ok_or_else(|| LineError::at(start + 1, "(", "unterminated marker"))?;
+
+    let marker: String = compressed_chars[start..=end].iter().collect();
+    let error = |message| LineError::at(start + 1, &marker, message);
+
+    let (repchars, repeats) = marker[1..marker.len() - 1].split_once('x').ok_or_else(|| error("invalid marker"))?;
+    let repchars = repchars.parse::<usize>().map_err(|_| error("invalid marker"))?;
+    let repeats = repeats.parse::<usize>().map_err(|_| error("invalid marker"))?;
+
+    if repchars > limit - end - 1 {
+        Err(error("marker extends past end of data"))?
+    }
+
+    Ok((repchars, repeats, end + 1))
+}
+
+#[test]
+fn test_uncompressed_len() {
+    let mut rng = Rng::new(9);
+
+    for _ in 0..200 {
+        let compressed = section(&mut rng, 3);
+
+        assert!(uncompressed_len(&compressed, 1).unwrap() == expand(&compressed, false).len(), "Part 1 failed for {}", compressed);
+        assert!(uncompressed_len(&compressed, 2).unwrap() == expand(&compressed, true).len(), "Part 2 failed for {}", compressed);
+    }
+}
+
+// Decompress by building the whole text
+#[cfg(test)]
+fn expand(compressed: &str, nested: bool) -> String {
+    let mut expanded = String::new();
+    let mut rest = compressed;
+
+    while let Some(start) = rest.find('(') {
+        expanded += &rest[..start];
+
+        let (marker, after) = rest[start + 1..].split_once(')').unwrap();
+        let (repchars, repeats) = marker.split_once('x').unwrap();
+        let (data, after) = after.split_at(repchars.parse().unwrap());
+        let data = if nested { expand(data, true) } else { data.to_string() };
+
+        expanded += &data.repeat(repeats.parse().unwrap());
+        rest = after;
+    }
+
+    expanded + rest
+}
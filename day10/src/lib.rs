@@ -0,0 +1,308 @@
+use aoc::{config, input::{Input, Source}, parse::{self, Words}, rng::Rng, solution::{Result, Solution}};
+use std::{collections::{HashMap, VecDeque}, rc::Rc};
+
+// The chips the puzzle asks about
+const LOW_CHIP: u16 = 17;
+const HIGH_CHIP: u16 = 61;
+
+pub fn default_input() -> Source {
+    Source::file("input10.txt")
+}
+
+// A network where chips only flow forwards, so every bot ends up with two chips.
+// One bot is given 17 and 61 directly and at least four chips reach outputs
+pub fn generate(rng: &mut Rng) -> String {
+    let bots = rng.range(10..=200) as usize;
+    let chosen = rng.below(bots - 2);
+
+    // Bot labels, and where each bot's two chips come from (None for a value line)
+    let mut labels: Vec<usize> = (0..bots).collect();
+    rng.shuffle(&mut labels);
+
+    let mut slots: Vec<[Option<usize>; 2]> = vec![[None, None]; bots];
+    let mut gives = Vec::new();
+    let mut outputs = 0;
+
+    for bot in 0..bots {
+        let mut dests = Vec::new();
+
+        for _ in 0..2 {
+            // A free slot in a later bot, apart from the one given 17 and 61
+            let free: Vec<(usize, usize)> = (bot + 1..bots).filter(|&b| b != chosen)
+                .flat_map(|b| (0..2).map(move |s| (b, s)))
+                .filter(|&(b, s)| slots[b][s].is_none())
+                .collect();
+
+            if !free.is_empty() && bot < bots - 2 && rng.chance(3, 4) {
+                let (b, s) = *rng.choose(&free);
+
+                slots[b][s] = Some(bot);
+                dests.push(format!("bot {}", labels[b]));
+            } else {
+                dests.push(format!("output {}", outputs));
+                outputs += 1;
+            }
+        }
+
+        gives.push(format!("bot {} gives low to {} and high to {}", labels[bot], dests[0], dests[1]));
+    }
+
+    // Distinct chip values for the value lines
+    let mut values: Vec<u64> = (1..=999).filter(|&v| v != LOW_CHIP as u64 && v != HIGH_CHIP as u64).collect();
+    rng.shuffle(&mut values);
+
+    let mut lines = gives;
+
+    for (bot, bot_slots) in slots.iter().enumerate() {
+        for (s, slot) in bot_slots.iter().enumerate() {
+            if slot.is_none() {
+                let value = match (bot == chosen, s) {
+                    (true, 0) => LOW_CHIP as u64,
+                    (true, _) => HIGH_CHIP as u64,
+                    _ => values.pop().expect("enough chip values")
+                };
+
+                lines.push(format!("value {} goes to bot {}", value, labels[bot]));
+            }
+        }
+    }
+
+    rng.shuffle(&mut lines);
+
+    lines.iter().map(|l| format!("{}\n", l)).collect()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Part1 = u16;
+    type Part2 = u64;
+
+    fn part1(&self, input: &str) -> Result<u16> {
+        let state = process(input)?;
+
+        Ok(state.compared.ok_or_else(|| format!("No robot compares {} with {}", state.compare[0], state.compare[1]))?)
+    }
+
+    fn part2(&self, input: &str) -> Result<u64> {
+        let state = process(input)?;
+
+        let output = |out| state.outputs.get(&out).map(|chip| *chip as u64).ok_or(format!("Nothing in output {}", out));
+
+        Ok(output(0)? * output(1)? * output(2)?)
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        parse_instructions(input)?;
+
+        Ok(())
+    }
+}
+
+fn process(input: &str) -> Result<State> {
+    let (inputs, bots) = parse_instructions(input)?;
+
+    let mut state = State {
+        bots,
+        giveq: inputs,
+        outputs: HashMap::new(),
+        compare: compare_chips()?,
+        compared: None
+    };
+
+    run_bots(&mut state)?;
+
+    Ok(state)
+}
+
+// The chips to look out for, lowest first, which can be set in the config
+fn compare_chips() -> Result<[u16; 2]> {
+    let low = config::get("day10.low_chip", LOW_CHIP)?;
+    let high = config::get("day10.high_chip", HIGH_CHIP)?;
+
+    if low >= high {
+        Err(format!("Low chip {} must be less than high chip {}", low, high))?
+    }
+
+    Ok([low, high])
+}
+
+struct State {
+    bots: HashMap<u16, Bot>,
+    giveq: VecDeque<Movement>,
+    outputs: HashMap<u16, u16>,
+    compare: [u16; 2],
+    compared: Option<u16>
+}
+
+struct Movement {
+    chip: u16,
+    to: Rc<Dest>
+}
+
+#[derive(Debug)]
+enum Dest {
+    Output(u16),
+    Bot(u16)
+}
+
+impl Dest {
+    fn parse(words: &mut Words) -> parse::Result<Dest> {
+        let typ = words.word("bot or output")?;
+        let num = words.parse("destination number")?;
+
+        match typ.text {
+            "output" => Ok(Dest::Output(num)),
+            "bot" => Ok(Dest::Bot(num)),
+            _ => Err(typ.error("expected bot or output, found"))
+        }
+    }
+}
+
+struct Bot {
+    num: u16,
+    lo_to: Rc<Dest>,
+    hi_to: Rc<Dest>,
+    chips: Vec<u16>
+}
+
+impl Bot {
+    fn give(&mut self, giveq: &mut VecDeque<Movement>, compare: [u16; 2], compared: &mut Option<u16>, chip: u16) {
+        self.chips.push(chip);
+
+        if self.chips.len() == 2 {
+            self.chips.sort();
+
+            if self.chips[..] == compare {
+                *compared = Some(self.num);
+            }
+
+            giveq.push_back(Movement {
+                chip: self.chips[0],
+                to: self.lo_to.clone()
+            });
+
+            giveq.push_back(Movement {
+                chip: self.chips[1],
+                to: self.hi_to.clone()
+            });
+
+            self.chips.clear();
+        }
+    }
+}
+
+fn run_bots(state: &mut State) -> Result<()> {
+    // Without a loop each chip can only pass each bot once
+    let max_moves = state.giveq.len() * (state.bots.len() + 1);
+    let mut moves = 0;
+
+    loop {
+        match state.giveq.pop_front(){
+            None => break,
+            Some(movement) => {
+                give(state, movement.chip, &movement.to);
+            }
+        }
+
+        moves += 1;
+
+        if moves > max_moves {
+            Err("Chips are passed round the bots in a loop")?
+        }
+    }
+
+    Ok(())
+}
+
+fn give(state: &mut State, chip: u16, dest: &Dest) {
+    match dest {
+        Dest::Output(out) => {
+            state.outputs.insert(*out, chip);
+        }
+        Dest::Bot(bot) => {
+            give_bot(state, *bot, chip);
+        }
+    }
+}
+
+fn give_bot(state: &mut State, bot: u16, chip: u16) {
+    let bot = state.bots.get_mut(&bot).unwrap();
+
+    bot.give(&mut state.giveq, state.compare, &mut state.compared, chip);
+}
+
+enum Instruction {
+    Bot(Bot),
+    Value(Movement)
+}
+
+impl Instruction {
+    fn parse(line: &str) -> parse::Result<Instruction> {
+        let mut words = Words::new(line);
+
+        let instruction = words.word("instruction")?;
+
+        let instruction = match instruction.text {
+            "bot" => {
+                let bot_no = words.parse("bot number")?;
+
+                words.keywords(&["gives", "low", "to"])?;
+                let lo = Dest::parse(&mut words)?;
+
+                words.keywords(&["and", "high", "to"])?;
+                let hi = Dest::parse(&mut words)?;
+
+                Instruction::Bot(Bot {
+                    num: bot_no,
+                    lo_to: Rc::new(lo),
+                    hi_to: Rc::new(hi),
+                    chips: Vec::new()
+                })
+            }
+            "value" => {
+                let val = words.parse("chip value")?;
+
+                words.keywords(&["goes", "to", "bot"])?;
+                let bot = words.parse("bot number")?;
+
+                Instruction::Value(Movement {
+                    chip: val,
+                    to: Rc::new(Dest::Bot(bot))
+                })
+            }
+            _ => Err(instruction.error("unrecognised instruction"))?
+        };
+
+        words.end()?;
+
+        Ok(instruction)
+    }
+}
+
+fn parse_instructions(input: &str) -> Result<(VecDeque<Movement>, HashMap<u16, Bot>)> {
+    let mut inputs = VecDeque::new();
+    let mut bots = HashMap::new();
+
+    for instruction in Input::from(input).parse_lines(Instruction::parse)? {
+        match instruction {
+            Instruction::Bot(bot) => {
+                bots.insert(bot.num, bot);
+            }
+            Instruction::Value(movement) => inputs.push_back(movement)
+        }
+    }
+
+    // Check all chips go somewhere
+    let dests = bots.values().flat_map(|b| vec![&*b.lo_to, &*b.hi_to]).chain(inputs.iter().map(|m| &*m.to));
+
+    for dest in dests {
+        if let Dest::Bot(num) = dest {
+            if !bots.contains_key(num) {
+                Err(format!("Bot {} has no instructions", num))?
+            }
+        }
+    }
+
+    Ok((inputs, bots))
+}
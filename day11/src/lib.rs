@@ -0,0 +1,782 @@
+use aoc::{input::{Input, Source as InputSource}, parse::{self, Token, Words}, rng::Rng, solution::{Result, Solution}};
+use log::{debug, trace};
+use search::{Flow, Space, Visited};
+use std::{fmt, hash::Hash, ops::{Deref, DerefMut}};
+
+/*
+F4
+F3                  TC
+F2               TG    RG RC CG CC
+F1 E SG SC PG PC
+*/
+
+pub fn default_input() -> InputSource {
+    InputSource::file("input11.txt")
+}
+
+const FLOOR_NAMES: [&str; 4] = ["first", "second", "third", "fourth"];
+
+// Up to five elements spread over the lower floors, safe to start with and solvable.
+// Elerium and dilithium are left for part 2
+pub fn generate(rng: &mut Rng) -> String {
+    loop {
+        let mut elements = SOURCE_VEC[..5].to_vec();
+        rng.shuffle(&mut elements);
+        elements.truncate(rng.range(1..=5) as usize);
+
+        // Floor of each element's generator and chip
+        let placed: Vec<(Source, usize, usize)> = elements.iter().map(|&e| (e, rng.below(3), rng.below(3))).collect();
+
+        // A chip can't share a floor with another generator unless its own is there
+        let safe = placed.iter().all(|&(_, gen, chip)| gen == chip || placed.iter().all(|&(_, other, _)| other != chip));
+
+        // The elevator needs something to carry
+        let movable = placed.iter().any(|&(_, gen, chip)| gen == 0 || chip == 0);
+
+        if !safe || !movable {
+            continue
+        }
+
+        let text: String = FLOOR_NAMES.iter().enumerate().map(|(floor, name)| {
+            let mut items = Vec::new();
+
+            for &(element, gen, chip) in &placed {
+                let element = format!("{:?}", element).to_lowercase();
+
+                if gen == floor {
+                    items.push(format!("a {} generator", element));
+                }
+
+                if chip == floor {
+                    items.push(format!("a {}-compatible microchip", element));
+                }
+            }
+
+            let contents = match items.len() {
+                0 => "nothing relevant".to_string(),
+                1 => items[0].clone(),
+                2 => format!("{} and {}", items[0], items[1]),
+                n => format!("{}, and {}", items[..n - 1].join(", "), items[n - 1])
+            };
+
+            format!("The {} floor contains {}.\n", name, contents)
+        }).collect();
+
+        let objects = parse_floors(&Input::from(text.as_str())).expect("generated floors parse");
+
+        if part1(&objects).is_some() && part2(&objects).is_some() {
+            return text
+        }
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn part1(&self, input: &str) -> Result<usize> {
+        Ok(part1(&parse_floors(&Input::from(input))?).ok_or("No solution")?)
+    }
+
+    fn part2(&self, input: &str) -> Result<usize> {
+        let objects = parse_floors(&Input::from(input))?;
+
+        if let Some((_, src, _)) = objects.iter().find(|(_, src, _)| matches!(src, Source::Elerium | Source::Dilithium)) {
+            Err(format!("{:?} is already in the facility, so can't be added for part 2", src))?
+        }
+
+        Ok(part2(&objects).ok_or("No solution")?)
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        parse_floors(&Input::from(input))?;
+
+        Ok(())
+    }
+}
+
+fn part1(objects: &[InputObject]) -> Option<usize> {
+    // Initialise state
+    let mut state: State = Default::default();
+
+    // Add input objects
+    add_input_objs(&mut state, objects);
+
+    min_moves(state)
+}
+
+fn part2(objects: &[InputObject]) -> Option<usize> {
+    // Initialise state
+    let mut state: State = Default::default();
+
+    // Add input objects
+    add_input_objs(&mut state, objects);
+
+    // Extra objects for part 2
+    state.add_obj(0, Source::Elerium, Type::Gen);
+    state.add_obj(0, Source::Elerium, Type::Chip);
+    state.add_obj(0, Source::Dilithium, Type::Gen);
+    state.add_obj(0, Source::Dilithium, Type::Chip);
+
+    min_moves(state)
+}
+
+// Floor, source and type of an object from the input
+type InputObject = (usize, Source, Type);
+
+fn add_input_objs(state: &mut State, objects: &[InputObject]) {
+    // Add objects (from input)
+    for (floor, src, typ) in objects {
+        state.add_obj(*floor, *src, *typ);
+    }
+}
+
+fn parse_floors(input: &Input) -> Result<Vec<InputObject>> {
+    let floors = input.parse_lines(|l| -> parse::Result<Vec<(Source, Type)>> {
+        let words: Vec<Token> = Words::new(l).map(|w| Token { text: w.text.trim_end_matches([',', '.']), ..w }).collect();
+        let mut objects = Vec::new();
+
+        for (i, w) in words.iter().enumerate().skip(1) {
+            let typ = match w.text {
+                "generator" => Type::Gen,
+                "microchip" => Type::Chip,
+                _ => continue
+            };
+
+            let name = Token { text: words[i - 1].text.trim_end_matches("-compatible"), ..words[i - 1] };
+            let src = Source::parse(name.text).ok_or_else(|| name.error("unsupported element"))?;
+
+            objects.push((src, typ));
+        }
+
+        Ok(objects)
+    })?;
+
+    if floors.len() != 4 {
+        Err(format!("Expected 4 floors, got {}", floors.len()))?
+    }
+
+    let objects: Vec<InputObject> = floors.into_iter().enumerate().flat_map(|(floor, objects)| {
+        objects.into_iter().map(move |(src, typ)| (floor, src, typ))
+    }).collect();
+
+    // Each object can only be in one place
+    let mut seen = 0;
+
+    for (_, src, typ) in &objects {
+        let bit = *Object::from_parts(src, typ);
+
+        if seen & bit != 0 {
+            Err(format!("{:?} {:?} is listed more than once", src, typ))?
+        }
+
+        seen |= bit;
+    }
+
+    Ok(objects)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Type {
+    Gen,
+    Chip
+}
+
+const GEN_SHIFT: u8 = 8;
+
+#[derive(Debug, Clone, Copy)]
+enum Source {
+    Strontium,
+    Plutonium,
+    Thulium,
+    Ruthenium,
+    Curium,
+    Elerium,
+    Dilithium
+}
+
+impl Source {
+    fn parse(name: &str) -> Option<Source> {
+        SOURCE_VEC.iter().find(|s| format!("{:?}", s).to_lowercase() == name).copied()
+    }
+}
+
+const SOURCE_VEC: [Source; 7] = [Source::Strontium, Source::Plutonium, Source::Thulium, Source::Ruthenium,
+    Source::Curium, Source::Elerium, Source::Dilithium];
+
+#[derive(Clone)]
+struct Floor(u16);
+
+impl Floor {
+    fn get_objects(&self) -> Vec<Object> {
+        let mut result = Vec::new();
+    
+        for e in SOURCE_VEC.iter() {
+            let src_u8 = *e as u8;
+            let cobj = Object(1 << src_u8);
+            let gobj = Object(1 << (GEN_SHIFT + src_u8));
+    
+            if self.0 & *cobj != 0 {
+                result.push(cobj)
+            }
+
+            if self.0 & *gobj != 0 {
+                result.push(gobj)
+            }
+        }
+    
+        result
+    }
+
+    fn empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    fn valid(&self) -> bool {
+        // Enumerate all chips
+        for e1 in SOURCE_VEC.iter() {
+            let src1_u8 = *e1 as u8;
+            let cbit1 = 1 << src1_u8;
+    
+            if self.0 & cbit1 != 0 {
+                // Got this chip - look for matching generator
+                let gbit1 = 1 << (GEN_SHIFT + src1_u8);
+    
+                if self.0 & gbit1 == 0 {
+                    // No generator for this chip - check there are no other generators
+                    for e2 in SOURCE_VEC.iter() {
+                        let src2_u8 = *e2 as u8;
+                        let gbit2 = 1 << (GEN_SHIFT + src2_u8);
+    
+                        if self.0 & gbit2 != 0 {
+                            // Got a generator
+                            return false
+                        }
+                    }
+                }
+            }
+        }
+    
+        true
+    }
+    
+    fn hash(&self) -> FloorHash {
+        let mut pairs: u8 = 0;
+        let mut chips: u8 = 0;
+        let mut gens: u8 = 0;
+    
+        for e in SOURCE_VEC.iter() {
+            let cbit = 1 << *e as u8;
+            let gbit = 1 << (GEN_SHIFT + *e as u8);
+    
+            let cmask = self.0 & cbit;
+            let gmask = self.0 & gbit;
+    
+            if cmask != 0{
+                if gmask != 0 {
+                    pairs += 1;
+                } else {
+                    chips += 1;
+                }
+            } else if gmask != 0 {
+                gens += 1;
+            }
+        }
+    
+        FloorHash::new(pairs, chips, gens)
+    }    
+}
+
+impl fmt::Debug for Floor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let obj = self.get_objects();
+        let list = obj.iter().map(|o| format!("{:?}", o)).collect::<Vec<String>>().join(", ");
+        f.write_fmt(format_args!("{}", list))
+    }
+}
+
+struct FloorHash(u16); // Actually 9 bits used
+
+impl FloorHash {
+    fn new(pairs: u8, chips: u8, gens: u8) -> FloorHash {
+        #[cfg(debug_assertions)]
+        assert!(pairs < 8 && chips < 8 && gens < 8);
+
+        FloorHash(((pairs as u16) << 6) | ((chips as u16) << 3) | (gens as u16))
+    }
+}
+
+impl Deref for FloorHash {
+    type Target = u16;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl fmt::Debug for FloorHash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!("[p={} c={} g={}]", self.0 >> 6, (self.0 >> 3) & 0x7, self.0 & 0x7))
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Map(Vec<Floor>);
+
+impl Map {
+    fn hash(&self, floor: usize) -> MapHash {
+        let mut hash: u64 = 0;
+    
+        // Shift in 9 bits per floor (total 27 bits)
+        for floor in &self.0 {
+            hash <<= 9;
+            hash |= *floor.hash() as u64;
+        }
+    
+        // Shift in current floor (2 bits => total 29 bits)
+        hash <<= 2;
+
+        #[cfg(debug_assertions)]
+        assert!(floor < 4);
+
+        hash |= floor as u64;
+
+        MapHash(hash)
+    }
+}
+
+impl Deref for Map {
+    type Target = Vec<Floor>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Map {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[derive(PartialEq, Eq, Clone)]
+struct MapHash(u64);
+
+impl Hash for MapHash {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write_u64(self.0)
+    }
+}
+
+impl fmt::Debug for MapHash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut tmp = self.0;
+        let mut outstr = String::from("[");
+
+        // Shift out floor
+        let floor = tmp & 0x3;
+        tmp >>= 2;
+
+        for i in (0..4).rev() {
+            outstr.push_str(&format!("{}", i));
+            if i == floor {
+                outstr.push('*');
+            }
+            outstr.push_str(&format!("={:?}", FloorHash((tmp & 0xffff) as u16)));
+            outstr.push(if i == 0 { ']'} else { ' ' });
+
+            tmp >>= 9;
+        }
+
+        f.write_str(&outstr)
+    }
+}
+
+#[derive(Clone)]
+struct Object(u16);
+
+impl Object {
+    fn from_parts(src: &Source, typ: &Type) -> Object {
+        let shift = match typ {
+            Type::Chip => 0,
+            Type::Gen => GEN_SHIFT
+        };
+    
+        #[cfg(debug_assertions)]
+        assert!((1 << *src as u8) < (1 << GEN_SHIFT));
+    
+        let bit = 1 << (shift + *src as u8);
+    
+        Object(bit)
+    }
+}
+
+impl Deref for Object {
+    type Target = u16;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Object {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for e in SOURCE_VEC.iter() {
+            let cbit = 1 << *e as u8;
+            let gbit = 1 << (GEN_SHIFT + *e as u8);
+
+            if self.0 & cbit != 0 {
+                return f.write_fmt(format_args!("{:?}-{:?}", *e, Type::Chip))
+            }
+
+            if self.0 & gbit != 0 {
+                return f.write_fmt(format_args!("{:?}-{:?}", *e, Type::Gen))
+            }
+        }
+
+        f.write_fmt(format_args!("Invalid object: {}", self.0))
+    }
+}
+
+#[derive(Clone, Debug)]
+struct State {
+    floor: usize,
+    map: Map,
+    score: usize
+}
+
+impl State {
+    fn add(&mut self, floor: usize, object: &Object) {
+        self.map[floor].0 |= object.0;
+        self.score += floor;
+    }
+
+    fn add_obj(&mut self, floor: usize, src: Source, typ: Type) {
+        self.add(floor, &Object::from_parts(&src, &typ))
+    }
+
+    fn remove(&mut self, floor: usize, object: &Object) {
+        self.map[floor].0 &= !object.0;
+        self.score -= floor;
+    }
+
+    fn make_move(&mut self, mv: Move) -> bool {
+        // Calculate new floor
+        let new_floor = match mv {
+            Move::One(dir, _) | Move::Two(dir, _, _) => {
+                (self.floor as isize + dir as isize) as usize
+            }
+        };
+
+        // Move out
+        self.move_out(self.floor, &mv);
+
+        // Validate
+        let from_floor = &mut self.map[self.floor];
+        if !from_floor.valid() {
+            return false
+        }
+    
+        // Move in
+        self.move_in(new_floor, &mv);
+
+        // Validate
+        let to_floor = &mut self.map[new_floor];
+        if !to_floor.valid() {
+            return false
+        }
+
+        // Set new floor
+        self.floor = new_floor;
+
+        true
+    }
+
+    fn move_out(&mut self, from: usize, mv: &Move) {
+        match mv {
+            Move::One(_, obj) => {
+                self.remove(from, obj);
+            }
+            Move::Two(_, obj1, obj2) => {
+                self.remove(from, obj1);
+                self.remove(from, obj2);
+            }
+        }
+    }
+
+    fn move_in(&mut self, to: usize, mv: &Move) {
+        match mv {
+            Move::One(_, obj) => {
+                self.add(to, obj);
+            }
+            Move::Two(_, obj1, obj2) => {
+                self.add(to, obj1);
+                self.add(to, obj2);
+            }
+        }
+    }
+
+    fn finished(&self) -> bool {
+        self.map[0].empty() && self.map[1].empty() && self.map[2].empty()
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State {
+            floor: 0,
+            map: Map(vec![Floor(0), Floor(0), Floor(0), Floor(0)]),
+            score: 0
+        }
+    }
+}
+
+#[derive(Clone)]
+enum Move {
+    One(i8, Object),
+    Two(i8, Object, Object)
+}
+
+impl fmt::Debug for Move {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let dir_str = match self {
+            Move::One(dir, _) | Move::Two(dir, _, _) => match dir {
+                1 => "Up",
+                -1 => "Down",
+                _ => "<Unknown>"
+            }
+        };
+
+        match self {
+            Move::One(_, i1) => f.write_fmt(format_args!("{} with {:?}", dir_str, i1)),
+            Move::Two(_, i1, i2) => f.write_fmt(format_args!("{} {:?} and {:?}", dir_str, i1, i2))
+        }
+    }
+}
+
+// States of the facility, moving the elevator one floor at a time
+struct Facility;
+
+impl Space for Facility {
+    type State = State;
+    type Key = MapHash;
+
+    fn neighbours(&self, state: &State) -> Vec<State> {
+        let mut result = Vec::new();
+
+        // Get current floor
+        let floor = &state.map[state.floor];
+
+        // Get items on this floor
+        let singles = floor.get_objects();
+
+        // Calculate combinations of objects that can move together
+        let combinations = calc_combinations(&singles);
+
+        let mut try_move = |mv: Move| {
+            let mut new_state = state.clone();
+
+            if new_state.make_move(mv) {
+                result.push(new_state);
+            }
+        };
+
+        if state.floor < 3 {
+            // Consider double moves up
+            for (i1, i2) in &combinations {
+                try_move(Move::Two(1, i1.clone(), i2.clone()));
+            }
+
+            // Consider single moves up
+            for i in &singles {
+                try_move(Move::One(1, i.clone()));
+            }
+        }
+
+        if state.floor > 0 {
+            // Consider single moves down
+            for i in &singles {
+                try_move(Move::One(-1, i.clone()));
+            }
+        }
+
+        result
+    }
+
+    // Equivalent states differ only in which element each pair belongs to
+    fn key(&self, state: &State) -> MapHash {
+        state.map.hash(state.floor)
+    }
+
+    // Each move takes at most two objects up one floor
+    fn estimate(&self, state: &State) -> usize {
+        let objects: usize = state.map.iter().map(|f| f.0.count_ones() as usize).sum();
+
+        ((objects * 3) - state.score).div_ceil(2)
+    }
+}
+
+fn min_moves(state: State) -> Option<usize> {
+    let path = search::astar(&Facility, state, Visited::Once, |visit| {
+        trace!("Visiting after {} moves: {:?}", visit.cost, visit.state);
+
+        Flow::stop_if(visit.state.finished())
+    })?;
+
+    for (moves, state) in path.states.iter().enumerate() {
+        let floors: Vec<String> = state.map.0.iter().enumerate().map(|(i, floor)| format!("F{}: {:?}", i + 1, floor)).collect();
+
+        debug!("After {} moves: elevator on F{}, {}", moves, state.floor + 1, floors.join("; "));
+    }
+
+    Some(path.cost)
+}
+
+fn calc_combinations(singles: &[Object]) -> Vec<(Object, Object)> {
+    let mut result = Vec::new();
+
+    for i in 0..singles.len() {
+        for j in i + 1..singles.len() {
+            result.push((singles[i].clone(), singles[j].clone()));
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_floor_valid() {
+        let mut f;
+        
+        // Valid floors
+        f = Floor(*Object::from_parts(&Source::Strontium, &Type::Chip));
+        assert!(f.valid());
+
+        f = Floor(*Object::from_parts(&Source::Strontium, &Type::Gen));
+        assert!(f.valid());
+
+        f = Floor(*Object::from_parts(&Source::Strontium, &Type::Gen) |
+                *Object::from_parts(&Source::Strontium, &Type::Chip));
+        assert!(f.valid());
+
+        f = Floor(*Object::from_parts(&Source::Strontium, &Type::Gen) |
+                *Object::from_parts(&Source::Strontium, &Type::Chip) |
+                *Object::from_parts(&Source::Thulium, &Type::Gen));
+        assert!(f.valid());
+
+        // Invalid floors
+        f = Floor(*Object::from_parts(&Source::Strontium, &Type::Chip) |
+                *Object::from_parts(&Source::Thulium, &Type::Gen) |
+                *Object::from_parts(&Source::Thulium, &Type::Chip));
+        assert!(!f.valid());
+
+        f = Floor(*Object::from_parts(&Source::Strontium, &Type::Chip) |
+                *Object::from_parts(&Source::Thulium, &Type::Gen));
+        assert!(!f.valid());
+
+        f = Floor(*Object::from_parts(&Source::Strontium, &Type::Chip) |
+                *Object::from_parts(&Source::Thulium, &Type::Gen) |
+                *Object::from_parts(&Source::Thulium, &Type::Chip) |
+                *Object::from_parts(&Source::Curium, &Type::Gen));
+        assert!(!f.valid());
+    }
+
+    #[test]
+    fn test_example() {
+        let mut state: State = Default::default();
+
+        state.add_obj(0, Source::Strontium, Type::Chip);
+        state.add_obj(0, Source::Curium, Type::Chip);
+
+        state.add_obj(1, Source::Strontium, Type::Gen);
+
+        state.add_obj(2, Source::Curium, Type::Gen);
+
+        assert!(min_moves(state) == Some(11));
+    }
+
+    #[test]
+    fn test_state_hash() {
+        let mut state1: State = Default::default();
+
+        let mut state2 = state1.clone();
+        let mut state3 = state1.clone();
+        let mut state4 = state1.clone();
+        let mut state5 = state1.clone();
+        let mut state6 = state1.clone();
+        let mut state7 = state1.clone();
+
+        state1.add_obj(0, Source::Curium, Type::Gen);
+        let hash1 = state1.map.hash(1);
+
+        state2.add_obj(0, Source::Plutonium, Type::Gen);
+        let hash2 = state2.map.hash(1);
+
+        assert!(hash1 == hash2);
+
+        state3.add_obj(0, Source::Plutonium, Type::Gen);
+        let hash3 = state3.map.hash(2);
+
+        assert!(hash2 != hash3);
+
+        state4.add_obj(0, Source::Plutonium, Type::Gen);
+        state4.add_obj(0, Source::Plutonium, Type::Chip);
+        let hash4 = state4.map.hash(2);
+
+        state5.add_obj(0, Source::Curium, Type::Gen);
+        state5.add_obj(0, Source::Curium, Type::Chip);
+        let hash5 = state5.map.hash(2);
+
+        assert!(hash4 == hash5);
+
+        state6.add_obj(3, Source::Strontium, Type::Chip);
+        state6.add_obj(3, Source::Plutonium, Type::Chip);
+        state6.add_obj(3, Source::Thulium, Type::Chip);
+        state6.add_obj(3, Source::Ruthenium, Type::Chip);
+        state6.add_obj(2, Source::Strontium, Type::Gen);
+        state6.add_obj(2, Source::Plutonium, Type::Gen);
+        state6.add_obj(2, Source::Ruthenium, Type::Gen);
+        state6.add_obj(2, Source::Curium, Type::Chip);
+        state6.add_obj(2, Source::Curium, Type::Gen);
+        state6.add_obj(0, Source::Thulium, Type::Gen);
+        let hash6 = state6.map.hash(0);
+
+        state7.add_obj(3, Source::Strontium, Type::Chip);
+        state7.add_obj(3, Source::Plutonium, Type::Chip);
+        state7.add_obj(3, Source::Thulium, Type::Chip);
+        state7.add_obj(3, Source::Ruthenium, Type::Chip);
+        state7.add_obj(2, Source::Strontium, Type::Gen);
+        state7.add_obj(2, Source::Plutonium, Type::Gen);
+        state7.add_obj(2, Source::Thulium, Type::Gen); // Swapped
+        state7.add_obj(2, Source::Curium, Type::Chip);
+        state7.add_obj(2, Source::Curium, Type::Gen);
+        state7.add_obj(0, Source::Ruthenium, Type::Gen); // Swapped
+        let hash7 = state7.map.hash(0);
+
+        assert!(hash6 == hash7);
+    }
+
+    #[test]
+    fn test_estimate() {
+        let mut state: State = Default::default();
+
+        // Three floors to go
+        state.add_obj(0, Source::Plutonium, Type::Gen);
+        assert!(Facility.estimate(&state) == 2);
+
+        // Two more floors to go
+        state.add_obj(1, Source::Plutonium, Type::Chip);
+        assert!(state.score == 1);
+        assert!(Facility.estimate(&state) == 3);
+
+        state.remove(0, &Object::from_parts(&Source::Plutonium, &Type::Gen));
+        state.add_obj(3, Source::Plutonium, Type::Gen);
+        assert!(Facility.estimate(&state) == 1);
+    }
+}
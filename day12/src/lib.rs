@@ -0,0 +1,59 @@
+use aoc::{config, input::Source, rng::Rng, solution::{Result, Solution}};
+use assembunny::{execute, MachineInt, Opcode, Program, State};
+
+pub fn default_input() -> Source {
+    Source::file("input12.txt")
+}
+
+// Same shape as the puzzle input: a Fibonacci loop, run further when c is set, plus a product
+pub fn generate(rng: &mut Rng) -> String {
+    let terms = rng.range(20..=28);
+    let extra = rng.range(5..=9);
+    let (x, y) = (rng.range(10..=20), rng.range(10..=20));
+
+    format!("cpy 1 a\ncpy 1 b\ncpy {} d\njnz c 2\njnz 1 5\ncpy {} c\ninc d\ndec c\njnz c -2\n\
+        cpy a c\ninc a\ndec b\njnz b -2\ncpy c b\ndec d\njnz d -6\n\
+        cpy {} c\ncpy {} d\ninc a\ndec d\njnz d -2\ndec c\njnz c -5\n", terms, extra, x, y)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Part1 = MachineInt;
+    type Part2 = MachineInt;
+
+    fn part1(&self, input: &str) -> Result<MachineInt> {
+        let mut program = parse_instructions(input)?;
+
+        let mut state: State = Default::default();
+        execute(&mut state, &mut program, config::get("day12.max_steps", MAX_STEPS)?)?;
+
+        Ok(state.reg[0])
+    }
+
+    fn part2(&self, input: &str) -> Result<MachineInt> {
+        let mut program = parse_instructions(input)?;
+
+        let mut state: State = Default::default();
+        state.reg[2] = 1;
+        execute(&mut state, &mut program, config::get("day12.max_steps", MAX_STEPS)?)?;
+
+        Ok(state.reg[0])
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        parse_instructions(input)?;
+
+        Ok(())
+    }
+}
+
+// Programs only copy, count and jump
+const OPCODES: &[Opcode] = &[Opcode::Cpy, Opcode::Inc, Opcode::Dec, Opcode::Jnz];
+
+// Instructions to run before giving up on a program finishing
+const MAX_STEPS: u64 = 100_000_000;
+
+fn parse_instructions(input: &str) -> Result<Program> {
+    assembunny::parse_instructions(input, OPCODES)
+}
@@ -0,0 +1,217 @@
+use aoc::{config, input::{Input, Source}, parse::Words, rng::Rng, solution::{Result, Solution}};
+use grid::Coord;
+use render::{Animation, Canvas, Draw, Rgb, BLACK, WHITE};
+use search::{Flow, Space, Visit, Visited};
+
+mod map;
+
+pub type Dist = u16;
+
+const FAV_NUM: &str = "1358";
+const MAP_DIM: usize = 60;
+const MAX_MAP_DIM: usize = 1000;
+const START: Coord = Coord { x: 1, y: 1 };
+const DEST: Coord = Coord { x: 31, y: 39 };
+const STEPS: Dist = 50;
+
+const SCALE: u16 = 8;
+
+const RED: Rgb = [0xa0, 0x00, 0x00];
+const GREEN: Rgb = [0x00, 0xa0, 0x00];
+const YELLOW: Rgb = [0xc0, 0xc0, 0x00];
+
+pub fn default_input() -> Source {
+    config::input("day13.input", FAV_NUM)
+}
+
+// A favourite number whose map has a way from the start to the destination
+pub fn generate(rng: &mut Rng) -> String {
+    loop {
+        let fav_num = rng.range(1..=9999) as u16;
+        let map = map::Map::generate(fav_num, MAP_DIM);
+        let maze = Maze { map: &map, dest: DEST };
+
+        if map.movable(DEST) && search::astar(&maze, START, Visited::Once, |visit| Flow::stop_if(*visit.state == DEST)).is_some() {
+            return format!("{}\n", fav_num)
+        }
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Part1 = Dist;
+    type Part2 = usize;
+
+    fn part1(&self, input: &str) -> Result<Dist> {
+        let map = generate_map(input)?;
+        let dest = Coord::new(config::get("day13.dest_x", DEST.x)?, config::get("day13.dest_y", DEST.y)?);
+
+        if !map.movable(dest) {
+            Err(format!("Destination {} is not an open space on the map", dest))?
+        }
+
+        Ok(shortest_path(&map, START, dest)?.ok_or("No path to the destination")?)
+    }
+
+    fn part2(&self, input: &str) -> Result<usize> {
+        let map = generate_map(input)?;
+
+        walk_for(&map, START, config::get("day13.steps", STEPS)?)
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        generate_map(input)?;
+
+        Ok(())
+    }
+}
+
+fn generate_map(input: &str) -> Result<map::Map> {
+    let fav_num = Input::from(input).parse_single_line(|l| Words::new(l).parse_only("favourite number"))?;
+    let dim = config::get("day13.map_dim", MAP_DIM)?;
+
+    if !(2..=MAX_MAP_DIM).contains(&dim) {
+        Err(format!("Map size {} must be between 2 and {}", dim, MAX_MAP_DIM))?
+    }
+
+    Ok(map::Map::generate(fav_num, dim))
+}
+
+// Open spaces of the map, moving towards a destination
+struct Maze<'a> {
+    map: &'a map::Map,
+    dest: Coord
+}
+
+impl<'a> Space for Maze<'a> {
+    type State = Coord;
+    type Key = Coord;
+
+    fn neighbours(&self, pos: &Coord) -> Vec<Coord> {
+        pos.neighbours().iter().copied().filter(|&n| self.map.movable(n)).collect()
+    }
+
+    fn key(&self, pos: &Coord) -> Coord {
+        *pos
+    }
+
+    fn estimate(&self, pos: &Coord) -> usize {
+        pos.manhattan_to(self.dest)
+    }
+}
+
+fn shortest_path(map: &map::Map, start: Coord, dest: Coord) -> Result<Option<Dist>> {
+    // Start animation
+    let mut animation = Animation::start("1", || blank(map), &[BLACK, WHITE, RED, GREEN, YELLOW], 3)?;
+
+    let maze = Maze { map, dest };
+    let mut explored = Vec::new();
+
+    let path = search::astar(&maze, start, Visited::Once, |visit| {
+        // Draw frame
+        if let Some(animation) = &mut animation {
+            draw_frame1(map, animation, visit);
+        }
+
+        if *visit.state == dest {
+            explored = visit.discovered().copied().collect();
+        }
+
+        Flow::stop_if(*visit.state == dest)
+    });
+
+    if let Some(animation) = animation {
+        animation.finish()?;
+    }
+
+    // Picture of the explored maze with the path taken
+    if let Some(path) = &path {
+        let cells = path.states.iter().map(|pos| (pos.x as usize, pos.y as usize)).collect::<Vec<_>>();
+
+        render::still("1", || blank(map), &[BLACK, WHITE, RED, GREEN], |draw| {
+            map.draw(draw, 1);
+            draw_cells(draw, &explored, 2);
+            draw.path(&cells, 3);
+        })?;
+    }
+
+    Ok(path.map(|path| path.cost as Dist))
+}
+
+fn blank(map: &map::Map) -> Result<Canvas> {
+    Canvas::scaled(map.dim() as u16, map.dim() as u16, SCALE)
+}
+
+fn draw_cells<'a>(draw: &mut dyn Draw, cells: impl IntoIterator<Item = &'a Coord>, colour: u8) {
+    for pos in cells {
+        draw.cell(pos.x as usize, pos.y as usize, colour);
+    }
+}
+
+fn draw_frame1(map: &map::Map, animation: &mut Animation, visit: &Visit<Coord>) {
+    animation.frame(|draw| {
+        // Draw walls in colour 1
+        map.draw(draw, 1);
+
+        // Draw visited in colour 2
+        draw_cells(draw, visit.discovered(), 2);
+
+        // Draw current path in colour 3
+        draw_cells(draw, visit.path(), 3);
+
+        // Draw queue in colour 4
+        draw_cells(draw, visit.frontier(), 4);
+    });
+}
+
+// Count the locations reachable in at most dist steps
+fn walk_for(map: &map::Map, start: Coord, dist: Dist) -> Result<usize> {
+    // Start animation
+    let mut animation = Animation::start("2", || blank(map), &[BLACK, WHITE, RED, YELLOW], 2)?;
+
+    // No destination, so the estimate is unused
+    let maze = Maze { map, dest: start };
+    let mut reached = Vec::new();
+
+    search::bfs(&maze, start, Visited::Once, |visit| {
+        reached.push(*visit.state);
+
+        // Draw frame
+        if let Some(animation) = &mut animation {
+            draw_frame2(map, animation, visit);
+        }
+
+        // Taken enough steps?
+        if visit.depth == dist as usize {
+            Flow::Prune
+        } else {
+            Flow::Continue
+        }
+    });
+
+    if let Some(animation) = animation {
+        animation.finish()?;
+    }
+
+    // Picture of the reachable locations
+    render::still("2", || blank(map), &[BLACK, WHITE, RED], |draw| {
+        map.draw(draw, 1);
+        draw_cells(draw, &reached, 2);
+    })?;
+
+    Ok(reached.len())
+}
+
+fn draw_frame2(map: &map::Map, animation: &mut Animation, visit: &Visit<Coord>) {
+    animation.frame(|draw| {
+        // Draw walls in colour 1
+        map.draw(draw, 1);
+
+        // Draw visited in colour 2
+        draw_cells(draw, visit.discovered(), 2);
+
+        // Draw queue in colour 3
+        draw_cells(draw, visit.frontier(), 3);
+    });
+}
@@ -1,67 +1,51 @@
 use std::fmt;
+use grid::{Coord, Grid};
+use render::Draw;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 enum Block {
     Space,
     Wall
 }
 
-#[derive(Default)]
 pub struct Map {
-    map: Vec<Vec<Block>>
+    map: Grid<Block>
 }
 
 impl Map {
     pub fn generate(seed: u16, dim: usize) -> Map {
-        let mut map: Map = Default::default();
+        let map = Grid::from_fn(dim, dim, |pos| {
+            let (x, y) = (pos.x as usize, pos.y as usize);
 
-        for y in 0..dim {
-            let mut row = Vec::new();
+            let mut calc = (x * x) + (3 * x) + (2 * x * y) + y + (y * y);
 
-            for x in 0..dim {
-                let mut calc = (x * x) + (3 * x) + (2 * x * y) + y + (y * y);
+            calc += seed as usize;
 
-                calc += seed as usize;
-
-                if calc.count_ones() % 2 == 0 {
-                    row.push(Block::Space)
-                } else {
-                    row.push(Block::Wall)
-                }
+            if calc.count_ones() % 2 == 0 {
+                Block::Space
+            } else {
+                Block::Wall
             }
+        });
 
-            map.map.push(row);
-        }
-
-        map
+        Map { map }
     }
 
-    pub fn movable(&self, x: usize, y: usize) -> bool {
-        match self.map[y][x] {
-            Block::Space => true,
-            Block::Wall => false
-        }
+    // Width and height
+    pub fn dim(&self) -> usize {
+        self.map.width()
     }
 
-    pub fn draw_gif(&self, frame_data: &mut [u8], mult: u8, frame_width: u16, colour: u8) {
-        // Build frame
-        for (y, row) in self.map.iter().enumerate() {
-            for (x, cell) in row.iter().enumerate() {
-                if let Block::Wall = *cell {
-                    self.draw_block(x as u16, y as u16, colour, frame_data, mult, frame_width)
-                }
-            }
-        }
+    // Off the map is not movable
+    pub fn movable(&self, pos: Coord) -> bool {
+        self.map.get(pos) == Some(&Block::Space)
     }
 
-    pub fn draw_block(&self, x: u16, y: u16, colour: u8, frame_data: &mut [u8], mult: u8, frame_width: u16) {
-        let gx_orgn = x as usize * mult as usize;
-        let gy_orgn = y as usize * mult as usize;
-
-        for gy in gy_orgn..gy_orgn + mult as usize {
-            let out_elem = (gy * frame_width as usize) + gx_orgn;
-            for i in 0..mult as usize {
-                frame_data[out_elem + i] = colour;
+    // Draw the walls in a colour
+    pub fn draw(&self, draw: &mut dyn Draw, colour: u8) {
+        for (pos, cell) in self.map.iter() {
+            if let Block::Wall = *cell {
+                draw.cell(pos.x as usize, pos.y as usize, colour)
             }
         }
     }
@@ -70,15 +54,9 @@ impl Map {
 impl fmt::Debug for Map {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str("\n")?;
-        for row in self.map.iter() {
-            let row_str = row.iter().map(|b| match b {
-                Block::Space => '.',
-                Block::Wall => '#'
-            }).collect::<String>();
-            f.write_str(&row_str)?;
-            f.write_str("\n")?;
-        }
-
-        Ok(())
+        f.write_str(&self.map.render(|b| match b {
+            Block::Space => '.',
+            Block::Wall => '#'
+        }))
     }
 }
@@ -0,0 +1,221 @@
+use std::fmt;
+
+use aoc::{input::{Input, Source}, parse::{self, Words}, rng::Rng, solution::{Result, Solution}};
+use log::debug;
+
+pub fn default_input() -> Source {
+    Source::file("input15.txt")
+}
+
+// Disc sizes are distinct primes so the discs always line up eventually. 11 is left for part 2
+pub fn generate(rng: &mut Rng) -> String {
+    let mut sizes = vec![2, 3, 5, 7, 13, 17, 19, 23];
+    rng.shuffle(&mut sizes);
+    sizes.truncate(rng.range(3..=7) as usize);
+
+    sizes.iter().enumerate().map(|(i, &size)| {
+        format!("Disc #{} has {} positions; at time=0, it is at position {}.\n", i + 1, size, rng.below(size))
+    }).collect()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn part1(&self, input: &str) -> Result<u64> {
+        let eqns = discs(input)?;
+
+        solve(&eqns)
+    }
+
+    fn part2(&self, input: &str) -> Result<u64> {
+        let mut eqns = discs(input)?;
+
+        // Extra disc for part 2
+        eqns.push(build_disc(11, 0, eqns.len() as u64 + 1));
+
+        solve(&eqns)
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        discs(input)?;
+
+        Ok(())
+    }
+}
+
+fn discs(input: &str) -> Result<Vec<ModEqn>> {
+    let eqns = parse_equations(&Input::from(input))?;
+
+    if eqns.is_empty() {
+        Err("No discs found")?
+    }
+
+    debug!("Disc equations: {:?}", eqns);
+
+    Ok(eqns)
+}
+
+#[derive(Clone, PartialEq)]
+struct ModEqn {
+    a: u64,
+    n: u64
+}
+
+impl ModEqn {
+    fn new(a: u64, n: u64) -> ModEqn {
+        ModEqn { a, n }
+    }
+}
+
+impl fmt::Debug for ModEqn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("x ≡ {} (mod {})", self.a, self.n))
+    }
+}
+
+fn solve(eqns_in: &[ModEqn]) -> Result<u64> {
+    // Sort by a descending
+    let mut eqns = eqns_in.to_vec();
+
+    eqns.sort_by(|e1, e2| {
+        e2.a.cmp(&e1.a)
+    });
+
+    let mut result = eqns[0].a;
+    let mut last_eqn = eqns[0].clone();
+
+    for eqn in eqns.iter().skip(1) {
+        debug!("Combining {:?} and {:?}", last_eqn, eqn);
+
+        // Steps of the cycle so far needed to line this disc up too
+        let steps = steps_to(result, last_eqn.n, eqn).ok_or("The discs never line up")?;
+
+        result = steps.checked_mul(last_eqn.n).and_then(|add| result.checked_add(add)).ok_or("Disc cycle is too long")?;
+
+        debug!("{} steps of {} to {}", steps, last_eqn.n, result);
+
+        // Disc sizes needn't be prime, so combine with the lowest common multiple
+        let n = (last_eqn.n / gcd(last_eqn.n, eqn.n)).checked_mul(eqn.n).ok_or("Disc cycle is too long")?;
+
+        last_eqn = ModEqn { a: result % n, n };
+    }
+
+    Ok(result)
+}
+
+// Lowest k with start + k * step ≡ a (mod n), if there is one
+fn steps_to(start: u64, step: u64, eqn: &ModEqn) -> Option<u64> {
+    let n = eqn.n as i128;
+    let target = (eqn.a as i128 - start as i128).rem_euclid(n);
+    let (g, inverse, _) = ext_gcd(step as i128 % n, n);
+
+    if target % g != 0 {
+        return None
+    }
+
+    let m = (n / g) as u128;
+    let k = ((target / g) as u128 * inverse.rem_euclid(m as i128) as u128) % m;
+
+    Some(k as u64)
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+// Greatest common divisor g of a and b, with x and y where a * x + b * y = g
+fn ext_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = ext_gcd(b, a % b);
+
+        (g, y, x - (a / b) * y)
+    }
+}
+
+fn parse_equations(input: &Input) -> Result<Vec<ModEqn>> {
+    let discs = input.parse_lines(parse_disc)?;
+
+    Ok(discs.into_iter().zip(1..).map(|((positions, startpos), time)| build_disc(positions, startpos, time)).collect())
+}
+
+// Returns number of positions and starting position
+fn parse_disc(line: &str) -> parse::Result<(u64, u64)> {
+    let mut words = Words::new(line);
+
+    words.keyword("Disc")?;
+    words.skip_words(1)?;
+    words.keyword("has")?;
+
+    let positions = words.word("positions")?;
+
+    if positions.parse::<u64>("positions")? == 0 {
+        Err(positions.error("disc needs positions, found"))?
+    }
+
+    words.keywords(&["positions;", "at", "time=0,", "it", "is", "at", "position"])?;
+
+    let startpos = words.word("start position")?.strip_suffix(".")?;
+
+    words.end()?;
+
+    Ok((positions.parse("positions")?, startpos.parse("start position")?))
+}
+
+fn build_disc(positions: u64, startpos: u64, time: u64) -> ModEqn {
+    // Time at which the disc reaches position 0, modulo its size
+    let n = positions as u128;
+    let a = (n * 2 - (startpos as u128 + time as u128) % n) % n;
+
+    ModEqn::new(a as u64, positions)
+}
+
+#[test]
+fn test_solve() {
+    // Test example from wikipedia: https://en.wikipedia.org/wiki/Chinese_remainder_theorem#Search_by_sieving
+    let eqns = vec![ModEqn::new(0, 3), ModEqn::new(3, 4), ModEqn::new(4, 5)];
+
+    assert!(solve(&eqns).unwrap() == 39);
+
+    // AOC example
+    let lines = [
+        "Disc #1 has 5 positions; at time=0, it is at position 4.", // =>  x ≡ (5 - 4) - timeoffset (mod 5)  =>  x ≡ 0 (mod 5)
+        "Disc #2 has 2 positions; at time=0, it is at position 1."  // =>  x ≡ (2 - 1) - timeoffset (mod 2)  =>  x ≡ 1 (mod 2)
+    ];
+    let eqns = parse_equations(&Input::from(lines.join("\n").as_str())).unwrap();
+    let expected_eqns = vec![ModEqn::new(0, 5), ModEqn::new(1, 2)];
+
+    assert!(eqns == expected_eqns);
+
+    assert!(solve(&eqns).unwrap() == 5);
+}
+
+#[test]
+fn test_solve_stepping() {
+    let mut rng = Rng::new(15);
+
+    for _ in 0..500 {
+        // Any sizes, so some discs never line up
+        let discs: Vec<(u64, u64)> = (0..rng.range(1..=4)).map(|_| {
+            let n = rng.range(1..=12);
+
+            (n, rng.range(0..=n - 1))
+        }).collect();
+
+        let eqns: Vec<ModEqn> = discs.iter().zip(1..).map(|(&(n, start), time)| build_disc(n, start, time)).collect();
+
+        assert!(solve(&eqns).ok() == first_time(&discs), "Failed for {:?}", discs);
+    }
+}
+
+// Try each time in turn until every disc lines up with the capsule. They've all cycled by the product of the sizes
+#[cfg(test)]
+fn first_time(discs: &[(u64, u64)]) -> Option<u64> {
+    let cycle: u64 = discs.iter().map(|&(n, _)| n).product();
+
+    (0..cycle).find(|t| discs.iter().zip(1..).all(|(&(n, start), offset)| (start + t + offset) % n == 0))
+}
@@ -0,0 +1,178 @@
+use aoc::{config, input::{Input, Source}, parse::{self, LineError, Words}, rng::Rng, solution::{Result, Solution}};
+
+const SEED: &str = "10111011111001111";
+const DISC_SIZE1: u32 = 272;
+const DISC_SIZE2: u32 = 35651584;
+
+pub fn default_input() -> Source {
+    config::input("day16.input", SEED)
+}
+
+pub fn generate(rng: &mut Rng) -> String {
+    let len = rng.range(10..=20);
+
+    (0..len).map(|_| if rng.chance(1, 2) { '1' } else { '0' }).chain(Some('\n')).collect()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Part1 = String;
+    type Part2 = String;
+
+    fn part1(&self, input: &str) -> Result<String> {
+        Ok(calc_checksum(&parse_seed(input)?, disc_size("day16.disc_size1", DISC_SIZE1)?))
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        Ok(calc_checksum(&parse_seed(input)?, disc_size("day16.disc_size2", DISC_SIZE2)?))
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        parse_seed(input)?;
+
+        Ok(())
+    }
+}
+
+// The checksum halves the data, so it needs an even length
+fn disc_size(key: &str, default: u32) -> Result<u32> {
+    let size = config::get(key, default)?;
+
+    if size == 0 || size % 2 == 1 {
+        Err(format!("Disc size {} must be even and more than 0", size))?
+    }
+
+    Ok(size)
+}
+
+fn parse_seed(input: &str) -> Result<String> {
+    let seed = Input::from(input).parse_single_line(|l| -> parse::Result<String> {
+        let mut words = Words::new(l);
+        let seed = words.word("seed")?;
+
+        if let Some((idx, c)) = seed.text.chars().enumerate().find(|(_, c)| *c != '0' && *c != '1') {
+            Err(LineError::at(seed.column + idx, &c.to_string(), "invalid seed character"))?
+        }
+
+        words.end()?;
+
+        Ok(seed.text.to_string())
+    })?;
+
+    Ok(seed)
+}
+
+fn calc_checksum(seed: &str, len: u32) -> String {
+    let mut pattern: Vec<bool> = pattern_from_string(seed);
+
+    while pattern.len() < len as usize {
+        mutate(&mut pattern);
+    }
+
+    pattern.truncate(len as usize);
+
+    let csum = checksum(&pattern);
+
+    pattern_to_string(&csum)
+}
+
+fn mutate(pattern: &mut Vec<bool>) {
+    let length = pattern.len();
+
+    pattern.push(false);
+
+    for b in (0..length).rev() {
+        pattern.push(!pattern[b]);
+    }
+}
+
+fn checksum(pattern: &[bool]) -> Vec<bool> {
+    let mut last = pattern.to_vec();
+    let mut checksum = Vec::new();
+
+    loop {
+        for i in (0..last.len()).step_by(2) {
+            if last[i] == last[i + 1] {
+                checksum.push(true);
+            } else {
+                checksum.push(false);
+            }
+        }
+
+        if checksum.len() % 2 == 1 {
+            break
+        }
+
+        last = checksum;
+        checksum = Vec::new();
+    }
+
+    checksum
+}
+
+fn pattern_from_string(string: &str) -> Vec<bool> {
+    string.chars().map(|c| match c {
+        '1' => true,
+        '0' => false,
+        _ => panic!("Invalid char")
+    }).collect()
+}
+
+fn pattern_to_string(vec: &[bool]) -> String {
+    vec.iter().map(|b| {
+        if *b { '1' } else { '0' }
+    }).collect::<String>()
+}
+
+#[test]
+fn test_process() {
+    fn test(before: &str, after: &str) {
+        let mut pattern = pattern_from_string(before);
+        mutate(&mut pattern);
+        let string = pattern_to_string(&pattern);
+        assert!(string == after);
+    }
+
+    test("1", "100");
+    test("0", "001");
+    test("11111", "11111000000");
+    test("111100001010", "1111000010100101011110000");
+}
+
+#[test]
+fn test_calc_checksum() {
+    assert!(calc_checksum("10000", 20) == "01100");
+
+    let mut rng = Rng::new(16);
+
+    for _ in 0..200 {
+        let seed = generate(&mut rng);
+        let seed = seed.trim_end();
+        let len = rng.range(1..=500) as u32 * 2;
+
+        assert!(calc_checksum(seed, len) == checksum_strings(seed, len), "Failed for {} length {}", seed, len);
+    }
+}
+
+// The dragon curve and checksum done with strings, following the puzzle text
+#[cfg(test)]
+fn checksum_strings(seed: &str, len: u32) -> String {
+    let mut data = seed.to_string();
+
+    while data.len() < len as usize {
+        let b: String = data.chars().rev().map(|c| if c == '0' { '1' } else { '0' }).collect();
+        data = format!("{}0{}", data, b);
+    }
+
+    let mut checksum = data[..len as usize].to_string();
+
+    loop {
+        let chars: Vec<char> = checksum.chars().collect();
+        checksum = chars.chunks(2).map(|pair| if pair[0] == pair[1] { '1' } else { '0' }).collect();
+
+        if checksum.len() % 2 == 1 {
+            break checksum
+        }
+    }
+}
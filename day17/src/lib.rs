@@ -0,0 +1,169 @@
+use aoc::{config, input::{Input, Source}, rng::Rng, solution::{Result, Solution}};
+use search::{Flow, Space, Visited};
+
+// Seed from input
+const SEED: &str = "gdjjyniy";
+
+const MAX_X: u8 = 3;
+const MAX_Y: u8 = 3;
+
+pub fn default_input() -> Source {
+    config::input("day17.input", SEED)
+}
+
+// A passcode with a way through to the vault
+pub fn generate(rng: &mut Rng) -> String {
+    loop {
+        let seed = rng.letters(8);
+
+        if shortest(&seed).is_some() {
+            return format!("{}\n", seed)
+        }
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Part1 = String;
+    type Part2 = usize;
+
+    fn part1(&self, input: &str) -> Result<String> {
+        Ok(shortest(&Input::from(input).single_line()?).ok_or("No path to the vault")?)
+    }
+
+    fn part2(&self, input: &str) -> Result<usize> {
+        Ok(longest(&Input::from(input).single_line()?))
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        Input::from(input).single_line()?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct WorkItem {
+    x: u8,
+    y: u8,
+    path: String
+}
+
+impl WorkItem {
+    fn at_vault(&self) -> bool {
+        self.x == MAX_X && self.y == MAX_Y
+    }
+}
+
+// The rooms, with the doors open depending on the path taken
+struct Vault<'a> {
+    seed: &'a str
+}
+
+impl<'a> Space for Vault<'a> {
+    type State = WorkItem;
+    type Key = ();
+
+    fn neighbours(&self, item: &WorkItem) -> Vec<WorkItem> {
+        // Reaching the vault ends the path
+        if item.at_vault() {
+            return Vec::new()
+        }
+
+        get_moves(item.x, item.y, self.seed, &item.path).into_iter().map(|m| WorkItem {
+            x: m.x,
+            y: m.y,
+            path: add_path(&item.path, m.dir)
+        }).collect()
+    }
+
+    // The path is part of the state so states never repeat
+    fn key(&self, _item: &WorkItem) {}
+}
+
+fn start() -> WorkItem {
+    WorkItem {
+        x: 0,
+        y: 0,
+        path: "".to_string()
+    }
+}
+
+fn shortest(seed: &str) -> Option<String> {
+    let path = search::bfs(&Vault { seed }, start(), Visited::Never, |visit| Flow::stop_if(visit.state.at_vault()))?;
+
+    path.states.last().map(|item| item.path.clone())
+}
+
+fn longest(seed: &str) -> usize {
+    let mut longest_len = 0;
+
+    search::dfs(&Vault { seed }, start(), Visited::Never, |visit| {
+        if visit.state.at_vault() && visit.state.path.len() > longest_len {
+            longest_len = visit.state.path.len();
+        }
+
+        Flow::Continue
+    });
+
+    longest_len
+}
+
+struct Move {
+    x: u8,
+    y: u8,
+    dir: char
+}
+
+fn get_moves(x: u8, y: u8, seed: &str, path: &String) -> Vec<Move> {
+    let mut moves = Vec::with_capacity(4);
+
+    // Calculate unlocked doors
+    let digest = md5::compute(format!("{}{}", seed, path));
+
+    let unlocked = |byte, shift: u8| (digest[byte] >> shift ) & 0x0f >= 0x0b;
+
+    let mut add = |x, y, dir| moves.push(Move { x, y, dir });
+
+    // Down
+    if y < MAX_Y && unlocked(0, 0) {
+        add(x, y + 1, 'D')
+    }
+
+    // Right
+    if x < MAX_X && unlocked(1, 0) {
+        add(x + 1, y, 'R')
+    }
+
+    // Up
+    if y > 0 && unlocked(0, 4) {
+        add(x, y - 1, 'U')
+    }
+
+    // Left
+    if x > 0 && unlocked(1, 4) {
+        add(x - 1, y, 'L')
+    }
+
+    moves
+}
+
+#[inline]
+fn add_path(old_path: &str, new_dir: char) -> String {
+    let mut new_path = String::with_capacity(old_path.len() + 1);
+
+    new_path.push_str(old_path);
+    new_path.push(new_dir);
+
+    new_path
+}
+
+#[test]
+fn test_walk() {
+    let shortest_path = shortest("ihgpwlah").unwrap();
+    assert!(shortest_path.as_str() == "DDRRRD");
+
+    let longest_len = longest("ihgpwlah");
+    assert!(longest_len == 370);
+}
@@ -0,0 +1,180 @@
+use aoc::{config, input::{Input, Source}, parse::LineError, rng::Rng, solution::{Result, Solution}};
+use grid::{Coord, Grid};
+use std::fmt;
+
+const ROWS1: usize = 40;
+const ROWS2: usize = 400000;
+
+pub fn default_input() -> Source {
+    Source::file("input18.txt")
+}
+
+pub fn generate(rng: &mut Rng) -> String {
+    (0..100).map(|_| if rng.chance(1, 2) { '^' } else { '.' }).chain(Some('\n')).collect()
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn part1(&self, input: &str) -> Result<usize> {
+        let map1 = Map::generate(&first_row(input)?, row_count("day18.rows1", ROWS1)?);
+
+        Ok(map1.count_safe())
+    }
+
+    fn part2(&self, input: &str) -> Result<usize> {
+        let map2 = Map::generate(&first_row(input)?, row_count("day18.rows2", ROWS2)?);
+
+        Ok(map2.count_safe())
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        first_row(input)?;
+
+        Ok(())
+    }
+}
+
+// The map includes the first row, so needs at least one
+fn row_count(key: &str, default: usize) -> Result<usize> {
+    let rows = config::get(key, default)?;
+
+    if rows == 0 {
+        Err(format!("Row count for {} must be more than 0", key))?
+    }
+
+    Ok(rows)
+}
+
+fn first_row(input: &str) -> Result<String> {
+    let row = Input::from(input).parse_single_line(|l| {
+        match l.chars().enumerate().find(|(_, c)| *c != '.' && *c != '^') {
+            Some((idx, c)) => Err(LineError::at(idx + 1, &c.to_string(), "unexpected character")),
+            None => Ok(l.to_string())
+        }
+    })?;
+
+    Ok(row)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Block {
+    Safe,
+    Trap
+}
+
+impl Block {
+    fn symbol(&self) -> char {
+        match self {
+            Block::Safe => '.',
+            Block::Trap => '^'
+        }
+    }
+}
+
+struct Map {
+    map: Grid<Block>
+}
+
+impl Map {
+    fn generate(line1: &str, line_cnt: usize) -> Map {
+        let cols = line1.len();
+        let mut map = Grid::new(cols, line_cnt, Block::Safe);
+
+        map.row_mut(0).copy_from_slice(&string_to_map_row(line1));
+
+        for y in 1..line_cnt as isize {
+            for x in 0..cols as isize {
+                // Off the edges counts as safe
+                let left = map.get(Coord::new(x - 1, y - 1)).unwrap_or(&Block::Safe);
+                let right = map.get(Coord::new(x + 1, y - 1)).unwrap_or(&Block::Safe);
+
+                if left != right {
+                    map[Coord::new(x, y)] = Block::Trap
+                }
+            }
+        }
+
+        Map { map }
+    }
+
+    fn count_safe(&self) -> usize {
+        self.map.iter().filter(|(_, &b)| b == Block::Safe).count()
+    }
+}
+
+impl fmt::Debug for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.map.render(Block::symbol))
+    }
+}
+
+fn string_to_map_row(string: &str) -> Vec<Block> {
+    string.chars().map(|c| match c {
+        '.' => Block::Safe,
+        '^' => Block::Trap,
+        _ => panic!("Unrecognised map character '{}'", c)
+    }).collect()
+}
+
+#[test]
+fn test_map_gen() {
+    let map = Map::generate("..^^.", 3);
+
+    assert!(map.map.render(Block::symbol) == [
+        "..^^.",
+        ".^^^^",
+        "^^..^",
+    ].map(|l| l.to_string() + "\n").concat());
+
+    let map = Map::generate(".^^.^.^^^^", 10);
+
+    assert!(map.map.render(Block::symbol) == [
+        ".^^.^.^^^^",
+        "^^^...^..^",
+        "^.^^.^.^^.",
+        "..^^...^^^",
+        ".^^^^.^^.^",
+        "^^..^.^^..",
+        "^^^^..^^^.",
+        "^..^^^^.^^",
+        ".^^^..^.^^",
+        "^^.^^^..^^",
+    ].map(|l| l.to_string() + "\n").concat())
+}
+
+#[test]
+fn test_map_gen_rules() {
+    let mut rng = Rng::new(18);
+
+    for _ in 0..100 {
+        let cols = rng.range(1..=30) as usize;
+        let first: String = (0..cols).map(|_| if rng.chance(1, 2) { '^' } else { '.' }).collect();
+        let rows = rng.range(1..=30) as usize;
+
+        assert!(Map::generate(&first, rows).map.render(Block::symbol) == rows_by_rules(&first, rows), "Failed for {}", first);
+    }
+}
+
+// Rows built with the four trap rules from the puzzle text
+#[cfg(test)]
+fn rows_by_rules(first: &str, count: usize) -> String {
+    let mut row: Vec<char> = first.chars().collect();
+    let mut rows = String::new();
+
+    for _ in 0..count {
+        rows.extend(row.iter().chain(Some(&'\n')));
+
+        let trap = |x: isize| x >= 0 && (x as usize) < row.len() && row[x as usize] == '^';
+
+        row = (0..row.len() as isize).map(|x| match (trap(x - 1), trap(x), trap(x + 1)) {
+            (true, true, false) | (false, true, true) | (true, false, false) | (false, false, true) => '^',
+            _ => '.'
+        }).collect();
+    }
+
+    rows
+}
@@ -0,0 +1,210 @@
+use aoc::{config, input::{Input, Source}, parse::Words, rng::Rng, solution::{Result, Solution}};
+use std::mem;
+#[cfg(test)]
+use std::collections::VecDeque;
+
+// Part 1 solution based on https://en.wikipedia.org/wiki/Josephus_problem
+// Part 2 uses the pattern of winners, checked against a simulation based on aceshades python solution
+
+const ELVES: &str = "3014387";
+
+pub fn default_input() -> Source {
+    config::input("day19.input", ELVES)
+}
+
+pub fn generate(rng: &mut Rng) -> String {
+    format!("{}\n", rng.range(1..=5_000_000))
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn part1(&self, input: &str) -> Result<usize> {
+        Ok(winner_fast(parse_elves(input)?))
+    }
+
+    fn part2(&self, input: &str) -> Result<usize> {
+        Ok(winner_opposite_fast(parse_elves(input)?))
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        parse_elves(input)?;
+
+        Ok(())
+    }
+}
+
+fn parse_elves(input: &str) -> Result<usize> {
+    let elves = Input::from(input).parse_single_line(|l| Words::new(l).parse_only("number of elves"))?;
+
+    if elves == 0 {
+        Err("No elves")?
+    }
+
+    Ok(elves)
+}
+
+fn winner_fast(elves: usize) -> usize {
+    // Remove most significant bit and shift a 1 in at the bottom
+    let next_power = 1 << (((mem::size_of::<usize>() * 8) - 1) - elves.leading_zeros() as usize);
+    ((elves & !next_power) << 1) | 1
+}
+
+fn winner_opposite_fast(elves: usize) -> usize {
+    // Winners restart from 1 after each power of 3, counting up by 1 then by 2
+    let mut power = 1;
+
+    while power <= elves / 3 {
+        power *= 3;
+    }
+
+    if elves == power {
+        elves
+    } else if elves - power <= power {
+        elves - power
+    } else {
+        (elves - power) + (elves - 2 * power)
+    }
+}
+
+#[cfg(test)]
+fn winner_opposite(elves: usize) -> usize {
+    let mut left = VecDeque::with_capacity((elves / 2) + 1);
+    let mut right = VecDeque::with_capacity((elves / 2) + 1);
+
+    for i in 0..elves {
+        if i < elves / 2 {
+            left.push_back(i + 1);
+        } else {
+            right.push_front(i + 1);
+        }
+    }
+
+    loop {
+        let llen = left.len();
+        let rlen = right.len();
+
+        if llen + rlen <= 1 {
+            break
+        }
+
+        // Steal
+        if llen > rlen {
+            left.pop_back().unwrap();
+        } else {
+            right.pop_back().unwrap();
+        }
+    
+        // Rotate
+        right.push_front(left.pop_front().unwrap());
+        left.push_back(right.pop_back().unwrap());
+    }
+
+    if let Some(winner) = left.pop_front() {
+        winner
+    } else {
+        right.pop_front().unwrap()
+    }
+}
+
+#[test]
+fn test_winner_fast() {
+    for i in 2..=100 {
+        assert!(winner_fast(i) == winner(i), "Test for {} failed", i);
+    }
+}
+
+#[test]
+fn test_winner_opposite() {
+    assert!(winner_opposite(5) == 2);
+    assert!(winner_opposite(6) == 3);
+    assert!(winner_opposite(7) == 5);
+
+    for i in 2..=100 {
+        assert!(winner_opposite(i) == winner_across(i), "Test for {} failed", i);
+    }
+
+    for i in 1..=1000 {
+        assert!(winner_opposite_fast(i) == winner_opposite(i), "Test for {} failed", i);
+    }
+}
+
+#[cfg(test)]
+fn winner(elves: usize) -> usize {
+    let mut presents = vec![1; elves];
+
+    let mut turn = 0;
+
+    loop {
+        if presents[turn] > 0 {
+            // Steal from who?
+            if let Some(steal) = steal_from(&presents, turn, 1) {
+                presents[turn] += presents[steal];
+                presents[steal] = 0;
+            } else {
+                // Found winner
+                break turn + 1
+            }
+        }
+
+        turn += 1;
+        if turn >= elves {
+            turn = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+fn steal_from(presents: &[usize], turn: usize, skip: usize) -> Option<usize> {
+    let mut steal = turn;
+    let mut left = skip;
+
+    loop {
+        steal = (steal + 1) % presents.len();
+
+        if steal == turn {
+            // No other players left
+            return None
+        }
+
+        if presents[steal] != 0 {
+            left -= 1;
+
+            if left == 0 {
+                break
+            }
+        }
+    }
+
+    Some(steal)
+}
+
+#[cfg(test)]
+fn winner_across(elves: usize) -> usize {
+    let mut presents = vec![1; elves];
+    let mut remaining = elves;
+
+    let mut turn = 0;
+
+    loop {
+        if presents[turn] > 0 {
+            // Steal from the elf opposite
+            if let Some(steal) = steal_from(&presents, turn, remaining / 2) {
+                presents[turn] += presents[steal];
+                presents[steal] = 0;
+                remaining -= 1;
+            } else {
+                // Found winner
+                break turn + 1
+            }
+        }
+
+        turn += 1;
+        if turn >= elves {
+            turn = 0;
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use std::{cmp, convert::TryFrom};
+use aoc::{config, input::{Input, Source}, parse::{self, Token}, rng::Rng, solution::{Result, Solution}};
+
+pub fn default_input() -> Source {
+    Source::file("input20.txt")
+}
+
+// Overlapping ranges covering every address apart from a few small gaps, in a random order
+pub fn generate(rng: &mut Rng) -> String {
+    let max = u32::MAX as u64;
+    let mut ranges = Vec::new();
+    let mut gaps = 0;
+    let mut next = 0;
+
+    while next <= max {
+        // At least one gap, by half way at the latest
+        let lo = if next > 0 && (rng.chance(1, 40) || (gaps == 0 && next > max / 2)) {
+            gaps += 1;
+            next + rng.range(1..=3)
+        } else {
+            next.saturating_sub(rng.range(0..=1_000_000))
+        }.min(max);
+
+        let hi = (lo + rng.range(1..=8_000_000)).min(max);
+
+        ranges.push(format!("{}-{}\n", lo, hi));
+        next = hi + 1;
+    }
+
+    rng.shuffle(&mut ranges);
+
+    ranges.concat()
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn part1(&self, input: &str) -> Result<u32> {
+        Ok(part1(&parse_ranges(input)?, max_address()?).ok_or("Every address is blocked")?)
+    }
+
+    fn part2(&self, input: &str) -> Result<u32> {
+        Ok(part2(&parse_ranges(input)?, max_address()?))
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        parse_ranges(input)?;
+
+        Ok(())
+    }
+}
+
+// The highest address, which can be set in the config
+fn max_address() -> Result<u32> {
+    config::get("day20.max_address", u32::MAX)
+}
+
+fn part1(ranges: &[Range], max: u32) -> Option<u32> {
+    // Wider than an address, so it can go past the last one
+    let mut lowest: u64 = 0;
+
+    for r in ranges {
+        if r.lo as u64 > lowest {
+            break
+        }
+        lowest = cmp::max(lowest, r.hi as u64 + 1);
+    }
+
+    u32::try_from(lowest).ok().filter(|&l| l <= max)
+}
+
+fn part2(ranges: &[Range], max: u32) -> u32 {
+    let mut lowest: u64 = 0;
+    let mut allowed: u64 = 0;
+
+    for r in ranges {
+        if r.lo > max {
+            break
+        }
+
+        if r.lo as u64 > lowest {
+            allowed += r.lo as u64 - lowest;
+        }
+
+        lowest = cmp::max(lowest, r.hi as u64 + 1);
+    }
+
+    // Addresses above the last range. Either max is below u32::MAX or at least one address is blocked, so the count fits
+    allowed += (max as u64 + 1).saturating_sub(lowest);
+
+    allowed as u32
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Range {
+    lo: u32,
+    hi: u32
+}
+
+impl Range {
+    fn parse(line: &str) -> parse::Result<Range> {
+        let line = Token::line(line);
+
+        match line.split('-')[..] {
+            [lo, hi] => {
+                let range = Range {
+                    lo: lo.parse("range start")?,
+                    hi: hi.parse("range end")?
+                };
+
+                if range.lo > range.hi {
+                    Err(line.error("range ends before it starts"))?
+                }
+
+                Ok(range)
+            }
+            _ => Err(line.error("expected range, found"))
+        }
+    }
+}
+
+fn parse_ranges(input: &str) -> Result<Vec<Range>> {
+    let mut ranges = Input::from(input).parse_lines(Range::parse)?;
+
+    if ranges.is_empty() {
+        Err("No ranges found")?
+    }
+
+    ranges.sort();
+
+    Ok(ranges)
+}
+
+#[test]
+fn test_range_sweep() {
+    const ADDRESSES: u32 = 1000;
+
+    let mut rng = Rng::new(20);
+
+    for _ in 0..200 {
+        // Ranges in a small space, with everything above it blocked
+        let mut ranges: Vec<Range> = (0..rng.range(1..=20)).map(|_| {
+            let lo = rng.range(0..=ADDRESSES as u64 - 1) as u32;
+            let hi = (lo + rng.range(0..=100) as u32).min(ADDRESSES - 1);
+
+            Range { lo, hi }
+        }).collect();
+
+        ranges.push(Range { lo: ADDRESSES, hi: u32::MAX });
+        ranges.sort();
+
+        // Bitmap of blocked addresses
+        let mut blocked = vec![false; ADDRESSES as usize];
+
+        for r in &ranges[..ranges.len() - 1] {
+            blocked[r.lo as usize..=r.hi as usize].fill(true);
+        }
+
+        let lowest = blocked.iter().position(|&b| !b).map_or(ADDRESSES, |p| p as u32);
+        let allowed = blocked.iter().filter(|&&b| !b).count() as u32;
+
+        assert!(part2(&ranges, u32::MAX) == allowed);
+        assert!(part1(&ranges, u32::MAX) == Some(lowest).filter(|&l| l < ADDRESSES));
+
+        // Or with the addresses above cut off instead of blocked
+        assert!(part2(&ranges[..ranges.len() - 1], ADDRESSES - 1) == allowed);
+        assert!(part1(&ranges[..ranges.len() - 1], ADDRESSES - 1) == Some(lowest).filter(|&l| l < ADDRESSES));
+    }
+}
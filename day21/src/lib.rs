@@ -0,0 +1,460 @@
+use aoc::{config, input::{Input, Source}, parse::{self, Words}, rng::Rng, solution::{Result, Solution}};
+
+const PASSWORD: &str = "abcdefgh";
+const SCRAMBLED: &str = "fbgdceah";
+
+pub fn default_input() -> Source {
+    Source::file("input21.txt")
+}
+
+// Instructions for the default 8 letter passwords
+pub fn generate(rng: &mut Rng) -> String {
+    let letters: Vec<char> = PASSWORD.chars().collect();
+    let count = rng.range(50..=100);
+
+    (0..count).map(|_| {
+        let mut pos = [0, 1, 2, 3, 4, 5, 6, 7];
+        rng.shuffle(&mut pos);
+
+        let (p1, p2) = (pos[0], pos[1]);
+        let (c1, c2) = (letters[p1], letters[p2]);
+        let steps = rng.range(1..=7);
+        let unit = if steps == 1 { "step" } else { "steps" };
+
+        match rng.below(7) {
+            0 => format!("swap position {} with position {}\n", p1, p2),
+            1 => format!("swap letter {} with letter {}\n", c1, c2),
+            2 => format!("rotate left {} {}\n", steps, unit),
+            3 => format!("rotate right {} {}\n", steps, unit),
+            4 => format!("rotate based on position of letter {}\n", c1),
+            5 => format!("reverse positions {} through {}\n", p1.min(p2), p1.max(p2)),
+            _ => format!("move position {} to position {}\n", p1, p2)
+        }
+    }).collect()
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Part1 = String;
+    type Part2 = String;
+
+    fn part1(&self, input: &str) -> Result<String> {
+        let instructions = parse_instructions(input)?;
+        let password = config::get("day21.password", PASSWORD.to_string())?;
+
+        check_password(&instructions, &password, true)?;
+
+        Ok(part1(&instructions, &password))
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let instructions = parse_instructions(input)?;
+        let scrambled = config::get("day21.scrambled", SCRAMBLED.to_string())?;
+
+        check_password(&instructions, &scrambled, false)?;
+
+        Ok(part2(&instructions, &scrambled))
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        parse_instructions(input)?;
+
+        Ok(())
+    }
+}
+
+// The instructions must fit the password, which can be set in the config
+fn check_password(instructions: &[Instruction], password: &str, forwards: bool) -> Result<()> {
+    let chars: Vec<char> = password.chars().collect();
+
+    // The rotations and reversals need something to move
+    if chars.len() < 2 {
+        Err(format!("Password {} needs at least 2 letters", password))?
+    }
+
+    if let Some((i, c)) = chars.iter().enumerate().find(|(i, c)| chars[..*i].contains(c)) {
+        Err(format!("Password {} repeats {} at position {}", password, c, i))?
+    }
+
+    for instr in instructions {
+        let (positions, letters) = match *instr {
+            Instruction::SwapPos(p1, p2) | Instruction::Reverse(p1, p2) | Instruction::Move(p1, p2) => (vec![p1, p2], vec![]),
+            Instruction::SwapChar(c1, c2) => (vec![], vec![c1, c2]),
+            Instruction::RotateRightPos(c) => (vec![], vec![c]),
+            Instruction::RotateLeftAmt(_) | Instruction::RotateRightAmt(_) => (vec![], vec![])
+        };
+
+        if let Some(p) = positions.iter().find(|&&p| p >= chars.len()) {
+            Err(format!("Password {} is too short for position {}", password, p))?
+        }
+
+        if let Some(c) = letters.iter().find(|c| !chars.contains(c)) {
+            Err(format!("Password {} has no letter {}", password, c))?
+        }
+
+        // Only 8 letters can be unrotated unambiguously
+        if !forwards && matches!(instr, Instruction::RotateRightPos(_)) && chars.len() != 8 {
+            Err(format!("Scrambled password {} must have 8 letters to unscramble", password))?
+        }
+    }
+
+    Ok(())
+}
+
+fn part1(instructions: &[Instruction], input: &str) -> String {
+    let mut chars = input.chars().collect();
+
+    for instr in instructions.iter() {
+        chars = instr.action(chars, true);
+    }
+
+    chars.iter().collect()
+}
+
+fn part2(instructions: &[Instruction], input: &str) -> String {
+    let mut chars = input.chars().collect();
+
+    for instr in instructions.iter().rev() {
+        chars = instr.action(chars, false);
+    }
+
+    chars.iter().collect()
+}
+
+#[derive(Debug)]
+enum Instruction {
+    SwapPos(usize, usize),
+    SwapChar(char, char),
+    RotateLeftAmt(usize),
+    RotateRightAmt(usize),
+    RotateRightPos(char),
+    Reverse(usize, usize),
+    Move(usize, usize),
+}
+
+impl Instruction {
+    fn parse(line: &str) -> parse::Result<Instruction> {
+        let mut words = Words::new(line);
+
+        let instr = words.word("instruction")?;
+
+        let instr = match instr.text {
+            "swap" => {
+                let what = words.word("position or letter")?;
+
+                match what.text {
+                    "position" => {
+                        let p1 = words.parse("position")?;
+                        words.keywords(&["with", "position"])?;
+                        Instruction::SwapPos(p1, words.parse("position")?)
+                    }
+                    "letter" => {
+                        let c1 = words.word("letter")?.char("letter")?;
+                        words.keywords(&["with", "letter"])?;
+                        Instruction::SwapChar(c1, words.word("letter")?.char("letter")?)
+                    }
+                    _ => Err(what.error("expected position or letter, found"))?
+                }
+            }
+            "rotate" => {
+                let how = words.word("left, right or based")?;
+
+                match how.text {
+                    "left" => Instruction::RotateLeftAmt(parse_steps(&mut words)?),
+                    "right" => Instruction::RotateRightAmt(parse_steps(&mut words)?),
+                    "based" => {
+                        words.keywords(&["on", "position", "of", "letter"])?;
+                        Instruction::RotateRightPos(words.word("letter")?.char("letter")?)
+                    }
+                    _ => Err(how.error("expected left, right or based, found"))?
+                }
+            }
+            "reverse" => {
+                words.keyword("positions")?;
+                let p1 = words.parse("position")?;
+                words.keyword("through")?;
+                let p2 = words.word("position")?;
+
+                if p2.parse::<usize>("position")? <= p1 {
+                    Err(p2.error("expected position after start, found"))?
+                }
+
+                Instruction::Reverse(p1, p2.parse("position")?)
+            }
+            "move" => {
+                words.keyword("position")?;
+                let p1 = words.parse("position")?;
+                words.keywords(&["to", "position"])?;
+                let p2 = words.word("position")?;
+
+                if p2.parse::<usize>("position")? == p1 {
+                    Err(p2.error("expected different position, found"))?
+                }
+
+                Instruction::Move(p1, p2.parse("position")?)
+            }
+            _ => Err(instr.error("unrecognised instruction"))?
+        };
+
+        words.end()?;
+
+        Ok(instr)
+    }
+
+    fn action(&self, chars: Vec<char>, forwards: bool) -> Vec<char> {
+        let mut new_chars = chars.clone();
+
+        let swap_pos = |chars: &mut Vec<char>, p1, p2| {
+            chars.swap(p1, p2);
+        };
+
+        let find_char = |chars: &Vec<char>, find_c| {
+            chars.iter().position(|c| *c == find_c).unwrap()
+        };
+
+        let rotate_l = |chars: &mut Vec<char>, p1, p2, amt| {
+            assert!(p1 < p2);
+            for _ in 0..amt {
+                let tmp = chars[p1];
+                for i in p1..p2 {
+                    chars[i] = chars[i + 1]
+                }
+                chars[p2] = tmp;
+            }
+        };
+
+        let rotate_r = |chars: &mut Vec<char>, p1, p2, amt| {
+            assert!(p1 < p2);
+            for _ in 0..amt {
+                let tmp = chars[p2];
+                for i in (p1..p2).rev() {
+                    chars[i + 1] = chars[i]
+                }
+                chars[p1] = tmp;
+            }
+        };
+
+        let reverse = |chars: &mut Vec<char>, mut p1, mut p2| {
+            assert!(p1 < p2);
+            while p1 < p2 {
+                chars.swap(p1, p2);
+
+                p1 += 1;
+                p2 -= 1;
+            }
+        };
+
+        match self {
+            Instruction::SwapPos(p1, p2) => swap_pos(&mut new_chars, *p1, *p2),
+            Instruction::SwapChar(c1, c2) => {
+                let p1 = find_char(&new_chars, *c1);
+                let p2 = find_char(&new_chars, *c2);
+                swap_pos(&mut new_chars, p1, p2);
+            }
+            Instruction::RotateLeftAmt(amt) => {
+                if forwards {
+                    rotate_l(&mut new_chars, 0, chars.len() - 1, *amt)
+                } else {
+                    rotate_r(&mut new_chars, 0, chars.len() - 1, *amt)
+                }
+            }
+            Instruction::RotateRightAmt(amt) => {
+                if forwards {
+                    rotate_r(&mut new_chars, 0, chars.len() - 1, *amt)
+                } else {
+                    rotate_l(&mut new_chars, 0, chars.len() - 1, *amt)
+                }
+            }
+            Instruction::RotateRightPos(c) => {
+                let calc_amt = |pos| 1 + if pos >= 4 { pos + 1 } else { pos };
+
+                if forwards {
+                    let pos = find_char(&new_chars, *c);
+                    rotate_r(&mut new_chars, 0, chars.len() - 1, calc_amt(pos));
+
+                } else {
+                    // Rotations by 'a' for 8 character strings:
+                    // Orig       Pos  Amt   Result     Pos
+                    // abcdefgh -> 0 -> 1 -> habcdefg -> 1
+                    // habcdefg -> 1 -> 2 -> fghabcde -> 3
+                    // ghabcdef -> 2 -> 3 -> defghabc -> 5
+                    // fghabcde -> 3 -> 4 -> bcdefgha -> 7
+                    // efghabcd -> 4 -> 6 -> ghabcdef -> 2
+                    // defghabc -> 5 -> 7 -> efghabcd -> 4
+                    // cdefghab -> 6 -> 8 -> cdefghab -> 6
+                    // bcdefgha -> 7 -> 9 -> abcdefgh -> 0
+
+                    // This yields a unique reverse position lookup for 8 character strings
+                    
+                    assert!(new_chars.len() == 8);
+
+                    let pos = find_char(&new_chars, *c);
+
+                    let orig_pos = if pos % 2 == 1 {
+                        (pos - 1) / 2
+                    } else {
+                        (((pos + 7) % 8) + 7) / 2
+                    };
+
+                    rotate_l(&mut new_chars, 0, chars.len() - 1, calc_amt(orig_pos));
+                }
+            }
+            Instruction::Reverse(p1, p2) => reverse(&mut new_chars, *p1, *p2),
+            Instruction::Move(p1, p2) => {
+                if p1 < p2 {
+                    if forwards {
+                        rotate_l(&mut new_chars, *p1, *p2, 1);
+                    } else {
+                        rotate_r(&mut new_chars, *p1, *p2, 1);
+                    }
+                } else if forwards {
+                    rotate_r(&mut new_chars, *p2, *p1, 1);
+                } else {
+                    rotate_l(&mut new_chars, *p2, *p1, 1);
+                }
+            }
+        }
+
+        new_chars
+    }
+}
+
+// Number of steps followed by "step" or "steps"
+fn parse_steps(words: &mut Words) -> parse::Result<usize> {
+    let steps = words.parse("steps")?;
+    let unit = words.word("'steps'")?;
+
+    if unit.text != "step" && unit.text != "steps" {
+        Err(unit.error("expected 'steps', found"))?
+    }
+
+    Ok(steps)
+}
+
+fn parse_instructions(input: &str) -> Result<Vec<Instruction>> {
+    Ok(Input::from(input).parse_lines(Instruction::parse)?)
+}
+
+#[test]
+fn test_example() {
+    let example = [
+        "swap position 4 with position 0",
+        "swap letter d with letter b",
+        "reverse positions 0 through 4",
+        "rotate left 1 step",
+        "move position 1 to position 4",
+        "move position 3 to position 0",
+        "rotate based on position of letter b",
+        "rotate based on position of letter d",
+    ];
+
+    let expected = [
+        "ebcda",
+        "edcba",
+        "abcde",
+        "bcdea",
+        "bdeac",
+        "abdec",
+        "ecabd",
+        "decab",
+    ];
+
+    let instructions = parse_instructions(&example.join("\n")).unwrap();
+
+    let input = "abcde";
+    let mut chars: Vec<char> = input.chars().collect();
+
+    println!("--- Forwards---");
+    for (idx, instr) in instructions.iter().enumerate() {
+        chars = instr.action(chars, true);
+
+        let string: String = chars.iter().collect();
+
+        println!("{:?} => {}", instr, string);
+
+        assert!(string.as_str() == expected[idx], "should be {}", expected[idx]);
+    }
+
+    println!("--- Backwards---");
+    for (idx, instr) in instructions.iter().enumerate().rev() {
+        match instr {
+            Instruction::RotateRightPos(_) => {
+                // This can't be reversed for 5 character strings - cheat
+                chars = expected[idx - 1].chars().collect();
+            }
+            _ => {
+                chars = instr.action(chars, false);
+            }
+        }
+
+        let string: String = chars.iter().collect();
+
+        println!("{:?} => {}", instr, string);
+        
+        if idx > 0 {
+            assert!(string.as_str() == expected[idx - 1], "should be {}", expected[idx - 1]);
+        } else {
+            assert!(string.as_str() == input, "should be {}", input);
+        }
+    }
+}
+
+#[test]
+fn test_reverse() {
+    let teststr = "abcdefgh";
+    let testchars: Vec<char> = teststr.chars().collect();
+
+    let test = |instr: Instruction| {
+        let mut chars = testchars.clone();
+
+        chars = instr.action(chars, true);
+        let fstring: String = chars.iter().collect();
+
+        chars = instr.action(chars, false);
+        let rstring: String = chars.iter().collect();
+
+        assert!(rstring.as_str() == teststr, "Instruction {:?} reversed {} to {}", instr, fstring, rstring);
+    };
+
+    for i in 0..6 {
+        for j in i + 1..7 {
+            test(Instruction::Move(i, j));
+            test(Instruction::Reverse(i, j));
+        }
+    }
+
+    for i in 0..7 {
+        for j in 0..7 {
+            if i != j {
+                test(Instruction::SwapChar(testchars[i], testchars[j]));
+                test(Instruction::Move(i, j));
+            }
+        }
+    }
+
+    for i in 1..=8 {
+        test(Instruction::RotateLeftAmt(i));
+        test(Instruction::RotateRightAmt(i));
+    }
+
+    for c in testchars.iter() {
+        test(Instruction::RotateRightPos(*c));
+    }
+}
+
+#[test]
+fn test_check_password() {
+    let instructions = parse_instructions("swap position 4 with position 0\nrotate based on position of letter d\n").unwrap();
+
+    assert!(check_password(&instructions, "abcde", true).is_ok());
+    assert!(check_password(&instructions, "abcd", true).unwrap_err().to_string() == "Password abcd is too short for position 4");
+    assert!(check_password(&instructions, "abcae", true).unwrap_err().to_string() == "Password abcae repeats a at position 3");
+    assert!(check_password(&instructions, "abcef", true).unwrap_err().to_string() == "Password abcef has no letter d");
+    assert!(check_password(&instructions, "abcde", false).is_err());
+    assert!(check_password(&instructions, "abcdefgh", false).is_ok());
+
+    let rotate = parse_instructions("rotate left 0 steps\n").unwrap();
+    assert!(check_password(&rotate, "a", true).unwrap_err().to_string() == "Password a needs at least 2 letters");
+    assert!(check_password(&rotate, "", false).is_err());
+}
@@ -0,0 +1,340 @@
+use std::{cmp, collections::HashSet};
+use grid::{Coord, Grid};
+use render::{Animation, Canvas, Draw, Rgb, BLACK, WHITE};
+use search::{Flow, Space, Visited};
+use aoc::{input::{Input, Source}, parse::{self, Words}, rng::Rng, solution::{Result, Solution}};
+
+pub fn default_input() -> Source {
+    Source::file("input22.txt")
+}
+
+// A grid like the puzzle's: one empty node below a wall of huge full nodes running to the right edge
+pub fn generate(rng: &mut Rng) -> String {
+    let width = rng.range(10..=38);
+    let height = rng.range(8..=30);
+    let wall_y = rng.range(2..=height / 2);
+    let wall_x = rng.range(1..=width / 2);
+    let empty = (rng.range(0..=width - 1), rng.range(wall_y + 1..=height - 1));
+
+    let mut text = "root@ebhq-gridcenter# df -h\nFilesystem              Size  Used  Avail  Use%\n".to_string();
+
+    for x in 0..width {
+        for y in 0..height {
+            let (size, used) = if (x, y) == empty {
+                (rng.range(85..=94), 0)
+            } else if y == wall_y && x >= wall_x {
+                (rng.range(500..=510), rng.range(490..=499))
+            } else {
+                (rng.range(85..=94), rng.range(64..=73))
+            };
+
+            let name = format!("/dev/grid/node-x{}-y{}", x, y);
+
+            text += &format!("{:<22} {:>4}T {:>4}T {:>5}T {:>4}%\n", name, size, used, size - used, used * 100 / size);
+        }
+    }
+
+    text
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Part1 = usize;
+    type Part2 = u16;
+
+    fn part1(&self, input: &str) -> Result<usize> {
+        Ok(part1(&servers(input)?))
+    }
+
+    fn part2(&self, input: &str) -> Result<u16> {
+        part2(servers(input)?)
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        servers(input)?;
+
+        Ok(())
+    }
+}
+
+fn servers(input: &str) -> Result<Vec<Server>> {
+    // Skip the command and column headings
+    let servers: Vec<Server> = Input::from(input).parse_lines(|l| {
+        if l.starts_with("/dev/") {
+            Server::parse(l).map(Some)
+        } else {
+            Ok(None)
+        }
+    })?.into_iter().flatten().collect();
+
+    if servers.is_empty() {
+        Err("No servers found")?
+    }
+
+    Ok(servers)
+}
+
+fn part1(servers: &[Server]) -> usize {
+    let mut pairs: HashSet<String> = HashSet::new();
+
+    for (a_idx, a) in servers.iter().enumerate() {
+        for (b_idx, b) in servers.iter().enumerate() {
+            if a_idx == b_idx {
+                continue
+            }
+
+            if a.used == 0 {
+                continue
+            }
+
+            if a.used > b.size - b.used {
+                continue
+            } 
+
+            pairs.insert(format!("{}-{}", cmp::min(a_idx, b_idx), cmp::max(a_idx, b_idx)));
+        }
+    }
+
+    pairs.len()
+}
+
+const SQUARE_SPACING: u16 = 3;
+
+const BLUE: Rgb = [0x00, 0x00, 0xff];
+const GREEN: Rgb = [0x00, 0xa0, 0x00];
+
+fn part2(servers: Vec<Server>) -> Result<u16> {
+    let max_size = servers.iter().map(|s| s.size).max().unwrap();
+    let mut map = build_map(servers)?;
+
+    // Picture size
+    let square = square_size(max_size);
+    let (cols, rows) = (map.width(), map.height());
+
+    // Start animation
+    let mut animation = Animation::start("", || blank(cols, rows, square), &[BLACK, BLUE, GREEN, WHITE], 10)?;
+
+    let mut draw = |map: &Map| {
+        if let Some(animation) = &mut animation {
+            animation.frame(|draw| map.draw(draw, square, SQUARE_SPACING));
+        }
+    };
+
+    // Draw initial map
+    draw(&map);
+
+    // Set up goal coords
+    let mut goal = Coord::new(map.width() as isize - 1, 0);
+
+    // Build goal path
+    let mut goal_path = Vec::new();
+    for x in 0..map.width() as isize - 1 {
+        goal_path.push(Coord::new(x, 0));
+    }
+
+    while let Some(next_goal) = goal_path.pop() {
+        // Calculate shortest path to next goal path
+        let space_path = shortest_path(&map, next_goal, goal).ok_or_else(|| format!("The empty node can't reach {} next to the goal data", next_goal))?;
+
+        for mv in space_path {
+            map.move_space(mv)?;
+
+            // Draw frame
+            draw(&map);
+        }
+
+        // Move goal to space
+        map.move_space(goal)?;
+
+        // Draw frame
+        draw(&map);
+
+        // Goal is now next goal
+        goal = next_goal;
+    }
+
+    if let Some(animation) = animation {
+        animation.finish()?;
+    }
+
+    // Picture of the final grid
+    render::still("", || blank(cols, rows, square), &[BLACK, BLUE, GREEN, WHITE], |draw| map.draw(draw, square, SQUARE_SPACING))?;
+
+    Ok(map.moves)
+}
+
+// Canvas for a grid of squares with spacing around them
+fn blank(cols: u16, rows: u16, square: u16) -> Result<Canvas> {
+    let size = |count: u16| count.checked_mul(square + SQUARE_SPACING).and_then(|s| s.checked_add(SQUARE_SPACING));
+
+    match (size(cols), size(rows)) {
+        (Some(width), Some(height)) => Ok(Canvas::new(width, height)),
+        _ => Err(format!("Grid of {}x{} is too large to draw", cols, rows))?
+    }
+}
+
+// Moves of the empty space, keeping clear of the goal data
+struct SpaceMoves<'a> {
+    map: &'a Map,
+    avoid: Coord
+}
+
+impl<'a> Space for SpaceMoves<'a> {
+    type State = Coord;
+    type Key = Coord;
+
+    fn neighbours(&self, pos: &Coord) -> Vec<Coord> {
+        self.map.map.neighbours(*pos).filter(|&next| next != self.avoid && self.map.valid_move(next, *pos)).collect()
+    }
+
+    fn key(&self, pos: &Coord) -> Coord {
+        *pos
+    }
+}
+
+fn shortest_path(map: &Map, to: Coord, avoid: Coord) -> Option<Vec<Coord>> {
+    let moves = SpaceMoves { map, avoid };
+    let path = search::bfs(&moves, map.empty, Visited::Once, |visit| Flow::stop_if(*visit.state == to))?;
+
+    // Path without the starting position
+    Some(path.states[1..].to_vec())
+}
+
+#[derive(Debug)]
+struct Server {
+    x: u16,
+    y: u16,
+    size: u16,
+    used: u16,
+}
+
+impl Server {
+    fn parse(line: &str) -> parse::Result<Server> {
+        let mut words = Words::new(line);
+
+        let dev = words.word("device")?;
+
+        let (x, y) = match dev.split('-')[..] {
+            [_, x, y] => (x.strip_prefix("x")?.parse("x position")?, y.strip_prefix("y")?.parse("y position")?),
+            _ => Err(dev.error("expected node-xN-yN, found"))?
+        };
+
+        let size_word = words.word("size")?.strip_suffix("T")?;
+        let size = size_word.parse("size")?;
+        let used_word = words.word("used")?.strip_suffix("T")?;
+        let used = used_word.parse("used")?;
+
+        if size == 0 {
+            Err(size_word.error("invalid node size"))?
+        }
+
+        if used > size {
+            Err(used_word.error("used more than node size, found"))?
+        }
+
+        // Available and use% follow
+        words.skip_words(2)?;
+        words.end()?;
+
+        Ok(Server { x, y, size, used })
+    }
+}
+
+struct Map {
+    map: Grid<Server>,
+    empty: Coord,
+    moves: u16
+}
+
+impl Map {
+    fn width(&self) -> u16 {
+        self.map.width() as u16
+    }
+
+    fn height(&self) -> u16 {
+        self.map.height() as u16
+    }
+
+    fn valid_move(&self, from: Coord, to: Coord) -> bool {
+        // Assert adjacent
+        assert!(from.manhattan_to(to) == 1);
+
+        // Check space
+        self.map[to].size > self.map[from].used
+    }
+
+    fn move_space(&mut self, from: Coord) -> Result<()> {
+        if !self.valid_move(from, self.empty) {
+            Err(format!("The data at {} doesn't fit in the empty node at {}", from, self.empty))?
+        }
+
+        // Move data
+        self.map[self.empty].used = self.map[from].used;
+        self.map[from].used = 0;
+        self.empty = from;
+
+        self.moves += 1;
+
+        Ok(())
+    }
+
+    fn draw(&self, draw: &mut dyn Draw, square: u16, border: u16) {
+        draw.clear(0);
+
+        for (pos, server) in self.map.iter() {
+            let outx = border + (pos.x as u16 * (square + border));
+            let outy = border + (pos.y as u16 * (square + border));
+
+            let size_size = square_size(server.size);
+            let offset = (square - size_size) / 2;
+
+            draw.outline(outx + offset - 1, outy + offset - 1, size_size + 2, size_size + 2, 3);
+            draw.rect(outx + offset, outy + offset, size_size, size_size, 1);
+            // Used never exceeds size, so the filled width fits back in a u16
+            let used_size = (server.used as u32 * size_size as u32) / server.size as u32;
+            draw.rect(outx + offset, outy + offset, used_size as u16, size_size, 2);
+        }
+    }
+}
+
+fn build_map(servers: Vec<Server>) -> Result<Map> {
+    let mut rows: Vec<Vec<Server>> = Vec::new();
+    let mut empty = None;
+
+    // Nodes can be listed in any order but must fill the grid
+    let mut servers = servers;
+    servers.sort_by_key(|s| (s.y, s.x));
+
+    for s in servers {
+        if s.y >= rows.len() as u16 {
+            if rows.len() as u16 != s.y {
+                Err(format!("No nodes in row {}", rows.len()))?
+            }
+
+            rows.push(Vec::new());
+        }
+
+        if rows[s.y as usize].len() as u16 != s.x {
+            Err(format!("Node x{}-y{} is missing or repeated", rows[s.y as usize].len(), s.y))?
+        }
+
+        if s.used == 0 {
+            empty = Some(Coord::new(s.x as isize, s.y as isize))
+        }
+
+        rows[s.y as usize].push(s);
+    }
+
+    Ok(Map {
+        map: Grid::from_rows(rows)?,
+        empty: empty.ok_or("No empty node")?,
+        moves: 0
+    })
+}
+
+fn square_size(val: u16) -> u16 {
+    let root = (val as f64).sqrt();
+    root.ceil() as u16
+}
+
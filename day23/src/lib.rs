@@ -0,0 +1,114 @@
+use aoc::{config, input::Source, rng::Rng, solution::{Result, Solution}};
+use assembunny::{Instruction, MachineInt, Opcode, Program, State};
+#[cfg(test)]
+use assembunny::{step, RegImm};
+
+pub fn default_input() -> Source {
+    Source::file("input23.txt")
+}
+
+// Same shape as the puzzle input: a factorial which toggles its own tail, then adds a product
+pub fn generate(rng: &mut Rng) -> String {
+    let (x, y) = (rng.range(70..=99), rng.range(70..=99));
+
+    format!("cpy a b\ndec b\ncpy a d\ncpy 0 a\ncpy b c\ninc a\ndec c\njnz c -2\ndec d\njnz d -5\n\
+        dec b\ncpy b c\ncpy c d\ndec d\ninc c\njnz d -2\ntgl c\ncpy -16 c\njnz 1 c\n\
+        cpy {} c\njnz {} d\ninc a\ninc d\njnz d -2\ninc c\njnz c -5\n", x, y)
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Part1 = MachineInt;
+    type Part2 = MachineInt;
+
+    fn part1(&self, input: &str) -> Result<MachineInt> {
+        part1(&parse_instructions(input)?)
+    }
+
+    fn part2(&self, input: &str) -> Result<MachineInt> {
+        part2(&parse_instructions(input)?)
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        parse_instructions(input)?;
+
+        Ok(())
+    }
+}
+
+fn part1(program: &[Instruction]) -> Result<MachineInt> {
+    let mut program1 = program.to_vec();
+    let mut state: State = Default::default();
+    state.reg[0] = 7;
+    execute(&mut state, &mut program1)?;
+    Ok(state.reg[0])
+}
+
+fn part2(program: &[Instruction]) -> Result<MachineInt> {
+    let mut program2 = program.to_vec();
+    let mut state: State = Default::default();
+    state.reg[0] = 12;
+    execute(&mut state, &mut program2)?;
+    Ok(state.reg[0])
+}
+
+// Programs can toggle their own instructions
+const OPCODES: &[Opcode] = &[Opcode::Cpy, Opcode::Inc, Opcode::Dec, Opcode::Jnz, Opcode::Tgl];
+
+// Instructions to run before giving up on a program finishing
+const MAX_STEPS: u64 = 100_000_000;
+
+fn parse_instructions(input: &str) -> Result<Program> {
+    assembunny::parse_instructions(input, OPCODES)
+}
+
+fn execute(state: &mut State, program: &mut Program) -> Result<()> {
+    assembunny::execute(state, program, config::get("day23.max_steps", MAX_STEPS)?)
+}
+
+#[test]
+fn test_exec() {
+    let lines = [
+        "cpy 2 a",
+        "tgl a",
+        "tgl a",
+        "tgl a",
+        "cpy 1 a",
+        "dec a",
+        "dec a",
+    ];
+
+    let mut program = parse_instructions(&lines.join("\n")).unwrap();
+
+    let mut state: State = Default::default();
+
+    let pstep = |state: &mut State, program: &mut Program| {
+        println!("----- step -----");
+        println!("{:?}", state);
+        println!("Executing: {:?}", program[state.pc as usize]);
+        step(state, program);
+        println!("{:?}", state);
+        println!("{:?}", program);
+    };
+
+    // cpy 2 a initializes register a to 2
+    pstep(&mut state, &mut program);
+    assert!(state.reg[0] == 2);
+
+    // tgl a modifies the instruction a (2) away from it, which changes the third tgl a into inc a
+    pstep(&mut state, &mut program);
+    assert!(program[3] == Instruction::Inc(RegImm::Reg(0)));
+
+    // tgl a modifies the instruction a (2) away from it, which changes the cpy 1 a into jnz 1 a
+    pstep(&mut state, &mut program);
+    assert!(program[4] == Instruction::Jnz(RegImm::Imm(1), RegImm::Reg(0)));
+
+    // The fourth instruction, which is now inc a, increments a to 3
+    pstep(&mut state, &mut program);
+    assert!(state.reg[0] == 3);
+
+    // The fifth instruction, which is now jnz 1 a, jumps a (3) instructions ahead, skipping the dec a instructions
+    pstep(&mut state, &mut program);
+    assert!(state.pc == 7);
+}
@@ -0,0 +1,405 @@
+mod map;
+use crate::map::*;
+
+use aoc::{input::{Input, Source}, rng::Rng, solution::{Result, Solution}};
+use std::{cmp, collections::HashMap};
+use render::{Animation, Canvas, Rgb, BLACK, WHITE};
+use grid::Coord;
+use search::{Flow, Space, Visited};
+
+pub fn default_input() -> Source {
+    Source::file("input24.txt")
+}
+
+// A carved maze with some walls knocked through for loops, and items 0 upwards in open spaces
+pub fn generate(rng: &mut Rng) -> String {
+    let (cols, rows) = (rng.range(10..=90) as usize, rng.range(5..=20) as usize);
+    let (width, height) = (cols * 2 + 1, rows * 2 + 1);
+    let mut maze = vec![vec![b'#'; width]; height];
+
+    // Depth first carve between cells at odd positions
+    let mut stack = vec![(0, 0)];
+    maze[1][1] = b'.';
+
+    while let Some(&(x, y)) = stack.last() {
+        let mut next = Vec::new();
+
+        if x > 0 { next.push((x - 1, y)) }
+        if x < cols - 1 { next.push((x + 1, y)) }
+        if y > 0 { next.push((x, y - 1)) }
+        if y < rows - 1 { next.push((x, y + 1)) }
+
+        next.retain(|&(nx, ny)| maze[ny * 2 + 1][nx * 2 + 1] == b'#');
+
+        if next.is_empty() {
+            stack.pop();
+        } else {
+            let (nx, ny) = *rng.choose(&next);
+
+            maze[y + ny + 1][x + nx + 1] = b'.';
+            maze[ny * 2 + 1][nx * 2 + 1] = b'.';
+            stack.push((nx, ny));
+        }
+    }
+
+    for _ in 0..cols * rows / 4 {
+        let (x, y) = (rng.range(1..=width as u64 - 2) as usize, rng.range(1..=height as u64 - 2) as usize);
+
+        if (x + y) % 2 == 1 {
+            maze[y][x] = b'.';
+        }
+    }
+
+    let items = rng.range(3..=7) as u8;
+    let mut placed = 0;
+
+    while placed <= items {
+        let (x, y) = (rng.below(cols) * 2 + 1, rng.below(rows) * 2 + 1);
+
+        if maze[y][x] == b'.' {
+            maze[y][x] = b'0' + placed;
+            placed += 1;
+        }
+    }
+
+    maze.into_iter().map(|row| String::from_utf8(row).unwrap() + "\n").collect()
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Part1 = u16;
+    type Part2 = u16;
+
+    fn part1(&self, input: &str) -> Result<u16> {
+        let map = parse_map(input)?;
+
+        draw_map(&map)?;
+
+        // Calculate shortest journey between items
+        let distances = calc_distances(&map)?;
+        let (dist, route) = journey(&distances, &map);
+
+        draw_route("1", &map, &distances, &route)?;
+
+        Ok(dist)
+    }
+
+    fn part2(&self, input: &str) -> Result<u16> {
+        let map = parse_map(input)?;
+
+        // Calculate shortest round trip
+        let distances = calc_distances(&map)?;
+        let (dist, route) = round_trip(&distances, &map);
+
+        draw_route("2", &map, &distances, &route)?;
+
+        Ok(dist)
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        parse_map(input)?;
+
+        Ok(())
+    }
+}
+
+fn parse_map(input: &str) -> Result<Map> {
+    let map = map::Map::parse(&Input::from(input))?;
+
+    if !map.items().contains_key(&0) {
+        Err("No starting point 0 on the map")?
+    }
+
+    // Items are numbered from 0 with none left out or repeated
+    if let Some(missing) = (0..).zip(map.items().keys()).find(|(n, item)| n != *item).map(|(n, _)| n) {
+        Err(format!("Item {} is missing from the map", missing))?
+    }
+
+    if map.item_count() != map.items().len() {
+        Err("Items appear more than once on the map")?
+    }
+
+    if map.items().len() < 2 {
+        Err("No items to visit besides 0")?
+    }
+
+    Ok(map)
+}
+
+const CYAN: Rgb = [0x00, 0xff, 0xff];
+const RED: Rgb = [0xff, 0x40, 0x40];
+
+fn draw_map(map: &Map) -> Result<()> {
+    // Start animation
+    if let Some(mut animation) = Animation::start("", || Canvas::scaled(map.width(), map.height(), 8), &[BLACK, WHITE, CYAN], 3)? {
+        animation.frame(|draw| map.draw(draw, 0, 1, 2));
+        animation.finish()?;
+    }
+
+    Ok(())
+}
+
+// Picture of the map with the route between the items in order
+fn draw_route(suffix: &str, map: &Map, distances: &Distances, route: &[u8]) -> Result<()> {
+    let cells = distances.route_path(route).iter().map(|pos| (pos.x as usize, pos.y as usize)).collect::<Vec<_>>();
+
+    render::still(suffix, || Canvas::scaled(map.width(), map.height(), 8), &[BLACK, WHITE, CYAN, RED], |draw| {
+        map.draw(draw, 0, 1, 2);
+        draw.path(&cells, 3);
+    })
+}
+
+#[derive(Debug)]
+struct Distances {
+    map: HashMap<(u8, u8), u16>, // Map 2 items to distance, smallest always first
+    paths: HashMap<(u8, u8), Vec<Coord>>, // Path from the smallest to the largest
+    to_find: Vec<u8>,
+}
+
+impl Distances {
+    fn new(size: u8) -> Distances {
+        let to_find = (size as u16 * (size as u16 - 1)) / 2;
+
+        Distances {
+            map: HashMap::with_capacity(to_find as usize),
+            paths: HashMap::with_capacity(to_find as usize),
+            to_find: vec![size - 1; size as usize],
+        }
+    }
+
+    // Path runs from item1 to item2
+    fn add(&mut self, item1: u8, item2: u8, mut path: Vec<Coord>) {
+        let min_item = cmp::min(item1, item2);
+        let max_item = cmp::max(item1, item2);
+        let steps = path.len() as u16 - 1;
+
+        if let Some(old_steps) = self.map.get(&(min_item, max_item)) {
+            if *old_steps != steps {
+                panic!("Distance insertion error")
+            }
+        } else {
+            self.map.insert((min_item, max_item), steps);
+
+            if item1 > item2 {
+                path.reverse();
+            }
+
+            self.paths.insert((min_item, max_item), path);
+
+            self.to_find[item1 as usize] -= 1;
+            self.to_find[item2 as usize] -= 1;
+        }
+    }
+
+    fn lookup(&self, item1: u8, item2: u8) -> u16 {
+        let min_item = cmp::min(item1, item2);
+        let max_item = cmp::max(item1, item2);
+
+        *self.map.get(&(min_item, max_item)).unwrap()
+    }
+
+    // Positions visited going between the items in order
+    fn route_path(&self, route: &[u8]) -> Vec<Coord> {
+        let mut result = Vec::new();
+
+        for leg in route.windows(2) {
+            let mut path = self.paths[&(cmp::min(leg[0], leg[1]), cmp::max(leg[0], leg[1]))].clone();
+
+            if leg[0] > leg[1] {
+                path.reverse();
+            }
+
+            // Each leg starts where the last ended
+            if !result.is_empty() {
+                path.remove(0);
+            }
+
+            result.extend(path);
+        }
+
+        result
+    }
+}
+
+fn calc_distances(map: &Map) -> Result<Distances> {
+    let mut distances = Distances::new(map.items().len() as u8);
+
+    for (item, pos) in map.items() {
+        walk_from(*item, *pos, map, &mut distances);
+
+        if distances.to_find[*item as usize] != 0 {
+            Err(format!("Not every item can be reached from item {}", item))?
+        }
+    }
+
+    Ok(distances)
+}
+
+// Open spaces of the map
+struct Moves<'a> {
+    map: &'a Map
+}
+
+impl<'a> Space for Moves<'a> {
+    type State = Coord;
+    type Key = Coord;
+
+    fn neighbours(&self, pos: &Coord) -> Vec<Coord> {
+        pos.neighbours().iter().copied().filter(|&next| self.map.valid_move(next)).collect()
+    }
+
+    fn key(&self, pos: &Coord) -> Coord {
+        *pos
+    }
+}
+
+fn walk_from(start_item: u8, start_pos: Coord, map: &Map, distances: &mut Distances) {
+    search::bfs(&Moves { map }, start_pos, Visited::Once, |visit| {
+        if let Some(item) = map.item_check(visit.state) {
+            if *item != start_item {
+                // Found an item
+                distances.add(start_item, *item, visit.path().into_iter().copied().collect());
+
+                // All found?
+                if distances.to_find[start_item as usize] == 0 {
+                    return Flow::Stop
+                }
+            }
+        }
+
+        Flow::Continue
+    });
+}
+
+// Shortest distance visiting every item from 0, with the items in order
+fn journey(distances: &Distances, map: &Map) -> (u16, Vec<u8>) {
+    let nodes = map.items().iter().filter_map(|(&item, _)| {
+        if item == 0 {
+            None
+        } else {
+            Some(item)
+        }
+    }).collect();
+
+    let mut opt_dist = u16::MAX;
+    let mut opt_route = Vec::new();
+
+    journey_iter(distances, &mut vec![0], nodes, 0, &mut opt_dist, &mut opt_route);
+
+    (opt_dist, opt_route)
+}
+
+fn journey_iter(distances: &Distances, route: &mut Vec<u8>, nodes: Vec<u8>, dist: u16, opt_dist: &mut u16, opt_route: &mut Vec<u8>) {
+    let last_node = *route.last().unwrap();
+
+    for n in &nodes {
+        // Get list of next nodes
+        let new_nodes: Vec<u8> = nodes.iter().cloned().filter(|new_n| *new_n != *n).collect();
+
+        // Calculate new distance (current distance plus distance of this leg)
+        let new_dist = dist + distances.lookup(last_node, *n);
+
+        route.push(*n);
+
+        if new_nodes.is_empty() {
+            // No more nodes - check against current optimal distance
+            if new_dist < *opt_dist {
+                // New optimal distance
+                *opt_dist = new_dist;
+                *opt_route = route.clone();
+            }
+        } else {
+            // Iterate in to other nodes
+            journey_iter(distances, route, new_nodes, new_dist, opt_dist, opt_route);
+        }
+
+        route.pop();
+    }
+}
+
+// Shortest distance visiting every item from 0 and back again, with the items in order
+fn round_trip(distances: &Distances, map: &Map) -> (u16, Vec<u8>) {
+    let nodes = map.items().iter().filter_map(|(&item, _)| {
+        if item == 0 {
+            None
+        } else {
+            Some(item)
+        }
+    }).collect();
+
+    let mut opt_dist = u16::MAX;
+    let mut opt_route = Vec::new();
+
+    round_trip_iter(distances, &mut vec![0], nodes, 0, &mut opt_dist, &mut opt_route);
+
+    (opt_dist, opt_route)
+}
+
+fn round_trip_iter(distances: &Distances, route: &mut Vec<u8>, nodes: Vec<u8>, dist: u16, opt_dist: &mut u16, opt_route: &mut Vec<u8>) {
+    let last_node = *route.last().unwrap();
+
+    for n in &nodes {
+        // Get list of next nodes
+        let new_nodes: Vec<u8> = nodes.iter().cloned().filter(|new_n| *new_n != *n).collect();
+
+        // Calculate new distance (current distance plus distance of this leg)
+        let mut new_dist = dist + distances.lookup(last_node, *n);
+
+        route.push(*n);
+
+        if new_nodes.is_empty() {
+            // No more nodes - travel back to the start
+            new_dist += distances.lookup(*n, 0);
+
+            // Check against current optimal distance
+            if new_dist < *opt_dist {
+                // New optimal distance
+                *opt_dist = new_dist;
+                *opt_route = route.clone();
+                opt_route.push(0);
+            }
+        } else {
+            // Iterate in to other nodes
+            round_trip_iter(distances, route, new_nodes, new_dist, opt_dist, opt_route);
+        }
+
+        route.pop();
+    }
+}
+
+#[test]
+fn test_dist() {
+    let lines = [
+        "###########",
+        "#0.1.....2#",
+        "#.#######.#",
+        "#4.......3#",
+        "###########",
+    ];
+
+    let map = map::Map::parse(&Input::from(lines.join("\n").as_str())).unwrap();
+
+    // Calculate distances between map items
+    let distances = calc_distances(&map).unwrap();
+
+    println!("Distances ({}): {:?}", distances.map.len(), distances);
+
+    assert!(distances.lookup(0, 1) == 2);
+    assert!(distances.lookup(0, 2) == 8);
+    assert!(distances.lookup(0, 3) == 10);
+    assert!(distances.lookup(0, 4) == 2);
+    assert!(distances.lookup(1, 2) == 6);
+    assert!(distances.lookup(1, 3) == 8);
+    assert!(distances.lookup(1, 4) == 4);
+    assert!(distances.lookup(2, 3) == 2);
+    assert!(distances.lookup(2, 4) == 10);
+    assert!(distances.lookup(3, 4) == 8);
+
+    let (opt_dist, route) = journey(&distances, &map);
+    println!("Optimal distance: {} via {:?}", opt_dist, route);
+
+    assert!(opt_dist == 14);
+    assert!(route == vec![0, 4, 1, 2, 3]);
+    assert!(distances.route_path(&route).len() == 15);
+}
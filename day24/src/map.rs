@@ -1,117 +1,86 @@
+use aoc::input::{self, Input};
+use grid::{Coord, Grid};
+use render::Draw;
 use std::collections::{BTreeMap, HashMap};
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
-pub struct Coord {
-    x: usize,
-    y: usize
-}
-
-impl Coord {
-    pub fn new(x: usize, y: usize) -> Coord {
-        Coord {x, y }
-    }
-
-    pub fn new_relative(&self, xadd: isize, yadd: isize) -> Coord {
-        Coord {
-            x: (self.x as isize + xadd) as usize,
-            y: (self.y as isize + yadd) as usize
-        }
-    }
-}
-
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 enum Block {
     Space,
     Wall,
 }
 
-#[derive(Default, Debug)]
+#[derive(Debug)]
 pub struct Map {
-    map: Vec<Vec<Block>>,
-    width: u16,
-    height: u16,
+    map: Grid<Block>,
     items: BTreeMap<u8, Coord>,
     item_pos: HashMap<Coord, u8>
 }
 
 impl Map {
-    pub fn parse(lines: &[String]) -> Map {
-        let mut map: Map = Default::default();
-
-        for (y, l) in lines.iter().enumerate() {
-            let row: Vec<Block> = l.chars().enumerate().map(|(x, c)| match c {
-                '.' => Block::Space,
-                '#' => Block::Wall,
-                _ => {
-                    let item = c as u8 - b'0';
-
-                    map.items.insert(item, Coord::new(x, y));
-                    map.item_pos.insert(Coord::new(x, y), item);
-
-                    Block::Space
-                }
-            }).collect();
-
-            map.map.push(row);
+    pub fn parse(input: &Input) -> input::Result<Map> {
+        // Cells are a block and possibly an item number
+        let cells = Grid::parse(input, |c| match c {
+            '.' => Some((Block::Space, None)),
+            '#' => Some((Block::Wall, None)),
+            _ => c.to_digit(10).map(|item| (Block::Space, Some(item as u8)))
+        })?;
+
+        let mut items = BTreeMap::new();
+        let mut item_pos = HashMap::new();
+
+        for (pos, (_, item)) in cells.iter() {
+            if let Some(item) = *item {
+                items.insert(item, pos);
+                item_pos.insert(pos, item);
+            }
         }
 
-        map.height = map.map.len() as u16;
-        map.width = map.map[0].len() as u16;
-
-        map
+        Ok(Map {
+            map: Grid::from_fn(cells.width(), cells.height(), |pos| cells[pos].0),
+            items,
+            item_pos
+        })
     }
 
-    pub fn draw(&self, frame_data: &mut[u8], frame_w: u16, block_size: u16, space_colour: u8, wall_colour: u8, item_colour: u8) {
-        // Draw map on to frame
-        
-        let mut draw_block = |x, y, colour| {
-            let mut outelemy = (y * block_size as usize * frame_w as usize) + (x * block_size as usize);
-
-            for _ in 0..block_size {
-                let mut outelemx = outelemy;
-
-                for _ in 0..block_size {
-                    frame_data[outelemx] = colour;
-                    outelemx += 1;
-                }
+    // Draw map on to a canvas scaled to the map size
+    pub fn draw(&self, draw: &mut dyn Draw, space_colour: u8, wall_colour: u8, item_colour: u8) {
+        for (pos, block) in self.map.iter() {
+            let colour = match block {
+                Block::Space => space_colour,
+                Block::Wall => wall_colour
+            };
 
-                outelemy += frame_w as usize;
-            }
-        };
-
-        for (my, row) in self.map.iter().enumerate() {
-            for (mx, block) in row.iter().enumerate() {
-                let colour = match block {
-                    Block::Space => space_colour,
-                    Block::Wall => wall_colour
-                };
-
-                draw_block(mx, my, colour);
-            }
+            draw.cell(pos.x as usize, pos.y as usize, colour);
         }
 
         // Draw items
         for (_, pos) in self.items.iter() {
-            draw_block(pos.x, pos.y, item_colour);
+            draw.cell(pos.x as usize, pos.y as usize, item_colour);
         }
     }
 
     pub fn width(&self) -> u16 {
-        self.width
+        self.map.width() as u16
     }
 
     pub fn height(&self) -> u16 {
-        self.height
+        self.map.height() as u16
     }
 
-    pub fn valid_move(&self, pos: &Coord) -> bool {
-        matches!(self.map[pos.y][pos.x], Block::Space)
+    // Off the map is not a valid move
+    pub fn valid_move(&self, pos: Coord) -> bool {
+        self.map.get(pos) == Some(&Block::Space)
     }
 
     pub fn items(&self) -> &BTreeMap<u8, Coord> {
         &self.items
     }
 
+    // Including any repeated items
+    pub fn item_count(&self) -> usize {
+        self.item_pos.len()
+    }
+
     pub fn item_check(&self, coord: &Coord) -> Option<&u8> {
         self.item_pos.get(coord)
     }
@@ -0,0 +1,122 @@
+use aoc::{config, input::Source, rng::Rng, solution::{Result, Solution}};
+use assembunny::{run, MachineInt, Opcode, Program, State};
+
+const SAMPLE_SIZE: u16 = 1000;
+
+// Instructions to run across every value of a tried, as a program may never send a clock signal
+const MAX_STEPS: u64 = 100_000_000;
+
+// Setting up the run for each value of a takes about as long as this many instructions
+const START_STEPS: u64 = 100;
+
+pub fn default_input() -> Source {
+    Source::file("input25.txt")
+}
+
+// Same shape as the puzzle input: a plus a product, sent out as binary over and over
+pub fn generate(rng: &mut Rng) -> String {
+    let (x, y) = (rng.range(4..=15), rng.range(100..=300));
+
+    format!("cpy a d\ncpy {} c\ncpy {} b\ninc d\ndec b\njnz b -2\ndec c\njnz c -5\ncpy d a\njnz 0 0\n\
+        cpy a b\ncpy 0 a\ncpy 2 c\njnz b 2\njnz 1 6\ndec b\ndec c\njnz c -4\ninc a\njnz 1 -7\n\
+        cpy 2 b\njnz c 2\njnz 1 4\ndec b\ndec c\njnz 1 -4\njnz 0 0\nout b\njnz a -19\njnz 1 -21\n", x, y)
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Part1 = MachineInt;
+    type Part2 = MachineInt;
+
+    fn part1(&self, input: &str) -> Result<MachineInt> {
+        lowest_clock(&parse_instructions(input)?)
+    }
+
+    fn part2(&self, _input: &str) -> Result<MachineInt> {
+        Err("Day 25 only has one part")?
+    }
+
+    fn parse(&self, input: &str) -> Result<()> {
+        parse_instructions(input)?;
+
+        Ok(())
+    }
+
+    fn has_part2(&self) -> bool {
+        false
+    }
+}
+
+// Find the lowest initial value of register 'a' which makes the program output a clock signal
+fn lowest_clock(program: &Program) -> Result<MachineInt> {
+    let max_steps = config::get("day25.max_steps", MAX_STEPS)?;
+    let mut budget = max_steps;
+
+    for init_a in 0..=MachineInt::MAX {
+        if budget == 0 {
+            Err(format!("No value of a below {} gives a clock signal within {} instructions", init_a, max_steps))?
+        }
+
+        budget = budget.saturating_sub(START_STEPS);
+
+        let mut program1 = program.clone();
+        let mut state: State = Default::default();
+        let mut signal = SignalState::Latch;
+
+        // Set register 'a'
+        state.reg[0] = init_a;
+
+        // Run the program for a limited time, stopping as soon as the signal is known to be good or bad
+        run(&mut state, &mut program1, &mut budget, |value| {
+            signal = next_signal(&signal, value);
+
+            matches!(signal, SignalState::Latch | SignalState::Good(..))
+        });
+
+        // Success?
+        if let SignalState::Perfect = signal {
+            return Ok(init_a)
+        }
+    }
+
+    Err("No value of a gives a clock signal")?
+}
+
+// Signal state after the next output value
+fn next_signal(signal: &SignalState, value: MachineInt) -> SignalState {
+    match *signal {
+        SignalState::Latch => {
+            if value == 1 || value == 0 {
+                SignalState::Good(1, value)
+            } else {
+                SignalState::Bad
+            }
+        }
+        SignalState::Good(num, last) => {
+            if (last == 1 && value == 0) || (last == 0 && value == 1) {
+                if num == SAMPLE_SIZE {
+                    SignalState::Perfect
+                } else {
+                    SignalState::Good(num + 1, value)
+                }
+            } else {
+                SignalState::Bad
+            }
+        }
+        _ => panic!("Unexpected state")
+    }
+}
+
+enum SignalState {
+    Latch,
+    Good(u16, MachineInt),
+    Bad,
+    Perfect
+}
+
+// Programs can toggle their own instructions and send out a signal
+const OPCODES: &[Opcode] = Opcode::ALL;
+
+fn parse_instructions(input: &str) -> Result<Program> {
+    assembunny::parse_instructions(input, OPCODES)
+}
@@ -0,0 +1,29 @@
+use aoc::input::Input;
+use aoc2016::DAYS;
+
+// Fuzz targets for every day: cargo fuzz run parse_dayNN or solve_dayNN.
+// Any input should give an answer or an error, never a panic or a hang
+
+// Input files are read as text, so other bytes never reach the parsers
+fn input(data: &[u8]) -> Option<Input> {
+    std::str::from_utf8(data).ok().map(|text| Input::from_text("fuzz", text))
+}
+
+pub fn parse(day_num: usize, data: &[u8]) {
+    if let Some(input) = input(data) {
+        let _ = (DAYS[day_num - 1].parse)(&input);
+    }
+}
+
+// Solve each part of input which parses
+pub fn solve(day_num: usize, data: &[u8]) {
+    let day = &DAYS[day_num - 1];
+
+    if let Some(input) = input(data) {
+        if (day.parse)(&input).is_ok() {
+            for part in day.parts() {
+                let _ = (day.solve)(&input, part);
+            }
+        }
+    }
+}
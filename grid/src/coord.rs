@@ -0,0 +1,110 @@
+use std::{fmt, ops::{Add, Sub}};
+
+// A position on a grid. Signed so walks can wander off the top or left
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Coord {
+    pub x: isize,
+    pub y: isize
+}
+
+impl Coord {
+    pub const ORIGIN: Coord = Coord { x: 0, y: 0 };
+
+    pub fn new(x: isize, y: isize) -> Coord {
+        Coord { x, y }
+    }
+
+    // One step in a direction
+    pub fn step(&self, dir: Dir) -> Coord {
+        *self + dir.offset()
+    }
+
+    // Several steps in a direction
+    pub fn steps(&self, dir: Dir, count: isize) -> Coord {
+        let offset = dir.offset();
+
+        Coord::new(self.x + offset.x * count, self.y + offset.y * count)
+    }
+
+    // The four orthogonal neighbours, in the order of Dir::ALL
+    pub fn neighbours(&self) -> [Coord; 4] {
+        let mut result = [*self; 4];
+
+        for (n, dir) in result.iter_mut().zip(Dir::ALL.iter()) {
+            *n = n.step(*dir);
+        }
+
+        result
+    }
+
+    // Distance from the origin moving orthogonally
+    pub fn manhattan(&self) -> usize {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+
+    pub fn manhattan_to(&self, other: Coord) -> usize {
+        (*self - other).manhattan()
+    }
+}
+
+impl Add for Coord {
+    type Output = Coord;
+
+    fn add(self, other: Coord) -> Coord {
+        Coord::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Coord {
+    type Output = Coord;
+
+    fn sub(self, other: Coord) -> Coord {
+        Coord::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl fmt::Display for Coord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+// Orthogonal directions, with y increasing downwards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir {
+    Up,
+    Down,
+    Left,
+    Right
+}
+
+impl Dir {
+    pub const ALL: [Dir; 4] = [Dir::Up, Dir::Down, Dir::Left, Dir::Right];
+
+    pub fn offset(&self) -> Coord {
+        match self {
+            Dir::Up => Coord::new(0, -1),
+            Dir::Down => Coord::new(0, 1),
+            Dir::Left => Coord::new(-1, 0),
+            Dir::Right => Coord::new(1, 0)
+        }
+    }
+
+    pub fn turn_left(&self) -> Dir {
+        match self {
+            Dir::Up => Dir::Left,
+            Dir::Left => Dir::Down,
+            Dir::Down => Dir::Right,
+            Dir::Right => Dir::Up
+        }
+    }
+
+    pub fn turn_right(&self) -> Dir {
+        match self {
+            Dir::Up => Dir::Right,
+            Dir::Right => Dir::Down,
+            Dir::Down => Dir::Left,
+            Dir::Left => Dir::Up
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use std::ops::{Index, IndexMut};
+use aoc::{input::{self, Input}, parse::LineError};
+
+mod coord;
+
+pub use coord::{Coord, Dir};
+
+// A rectangular grid of cells addressed by Coord, with (0, 0) at the top left
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where T: Clone {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height]
+        }
+    }
+
+    // Build each cell from its position
+    pub fn from_fn<F>(width: usize, height: usize, mut cell: F) -> Grid<T>
+    where F: FnMut(Coord) -> T {
+        let mut cells = Vec::with_capacity(width * height);
+
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(cell(Coord::new(x as isize, y as isize)));
+            }
+        }
+
+        Grid { width, height, cells }
+    }
+
+    // Build from rows, which must all be the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>, String> {
+        let width = rows.first().map_or(0, |r| r.len());
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                Err(format!("Row {} has {} cells, expected {}", y, row.len(), width))?
+            }
+
+            cells.extend(row);
+        }
+
+        Ok(Grid { width, height, cells })
+    }
+
+    // Parse non-empty lines as rows, each char converted by the cell function
+    pub fn parse<F>(input: &Input, mut cell: F) -> input::Result<Grid<T>>
+    where F: FnMut(char) -> Option<T> {
+        let mut width = None;
+
+        let rows = input.parse_lines(|l| {
+            let mut row = Vec::with_capacity(l.len());
+
+            for (col, c) in l.chars().enumerate() {
+                match cell(c) {
+                    Some(t) => row.push(t),
+                    None => Err(LineError::at(col + 1, &c.to_string(), "unexpected character"))?
+                }
+            }
+
+            match width {
+                None => width = Some(row.len()),
+                Some(w) if w != row.len() => Err(format!("row is {} cells long, expected {}", row.len(), w))?,
+                _ => {}
+            }
+
+            Ok::<_, LineError>(row)
+        })?;
+
+        if rows.is_empty() {
+            Err(input::InputError::Empty { file: input.name().to_string() })?
+        }
+
+        Ok(Grid::from_rows(rows).expect("rows checked while parsing"))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Coord) -> bool {
+        self.offset(pos).is_some()
+    }
+
+    pub fn get(&self, pos: Coord) -> Option<&T> {
+        self.offset(pos).map(|o| &self.cells[o])
+    }
+
+    pub fn get_mut(&mut self, pos: Coord) -> Option<&mut T> {
+        self.offset(pos).map(move |o| &mut self.cells[o])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on zero
+        self.cells.chunks(self.width.max(1))
+    }
+
+    // All positions, row by row
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Coord::new(x as isize, y as isize)))
+    }
+
+    // All cells with their positions, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.coords().zip(self.cells.iter())
+    }
+
+    // Orthogonal neighbours which are on the grid
+    pub fn neighbours(&self, pos: Coord) -> impl Iterator<Item = Coord> + '_ {
+        pos.neighbours().to_vec().into_iter().filter(move |&n| self.contains(n))
+    }
+
+    // Position of the first cell matching the predicate
+    pub fn position<F>(&self, mut pred: F) -> Option<Coord>
+    where F: FnMut(&T) -> bool {
+        self.iter().find(|(_, c)| pred(c)).map(|(pos, _)| pos)
+    }
+
+    // Render as text, one line per row
+    pub fn render<F>(&self, mut cell: F) -> String
+    where F: FnMut(&T) -> char {
+        let mut result = String::with_capacity((self.width + 1) * self.height);
+
+        for row in self.rows() {
+            result.extend(row.iter().map(&mut cell));
+            result.push('\n');
+        }
+
+        result
+    }
+
+    fn offset(&self, pos: Coord) -> Option<usize> {
+        if pos.x < 0 || pos.y < 0 || pos.x as usize >= self.width || pos.y as usize >= self.height {
+            None
+        } else {
+            Some(pos.y as usize * self.width + pos.x as usize)
+        }
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Coord) -> &T {
+        match self.offset(pos) {
+            Some(o) => &self.cells[o],
+            None => panic!("{} is outside {}x{} grid", pos, self.width, self.height)
+        }
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, pos: Coord) -> &mut T {
+        match self.offset(pos) {
+            Some(o) => &mut self.cells[o],
+            None => panic!("{} is outside {}x{} grid", pos, self.width, self.height)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid() {
+        let input = Input::from_text("test", "#.#\n...\n");
+        let grid = Grid::parse(&input, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None
+        }).unwrap();
+
+        assert!(grid.width() == 3 && grid.height() == 2);
+        assert!(grid[Coord::new(2, 0)]);
+        assert!(grid.get(Coord::new(3, 0)).is_none());
+        assert!(grid.get(Coord::new(0, -1)).is_none());
+        assert!(grid.neighbours(Coord::ORIGIN).collect::<Vec<_>>() == vec![Coord::new(0, 1), Coord::new(1, 0)]);
+        assert!(grid.render(|&c| if c { '#' } else { '.' }) == "#.#\n...\n");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let ragged = Input::from_text("test", "..\n...\n");
+        let err = Grid::parse(&ragged, |_| Some(())).unwrap_err();
+        assert!(err.to_string() == "test: line 2: row is 3 cells long, expected 2");
+
+        let bad = Input::from_text("test", "..\n.x\n");
+        let err = Grid::parse(&bad, |c| if c == '.' { Some(()) } else { None }).unwrap_err();
+        assert!(err.to_string() == "test: line 2, column 2: unexpected character 'x'");
+    }
+
+    #[test]
+    fn test_coord() {
+        let mut dir = Dir::Up;
+        let mut pos = Coord::ORIGIN;
+
+        for _ in 0..3 {
+            dir = dir.turn_right();
+            pos = pos.steps(dir, 2);
+        }
+
+        assert!(dir == Dir::Left);
+        assert!(pos == Coord::new(0, 2));
+        assert!(pos.manhattan_to(Coord::new(3, -1)) == 6);
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{canvas::Canvas, gif_file::GifFile, options, output_path, terminal::Terminal, Draw, Rgb};
+use aoc::solution::Result;
+
+// Frames of a day's drawing, sent to whichever outputs were asked for.
+// Only exists when there is at least one, so frames needn't be drawn otherwise
+pub struct Animation {
+    canvas: Canvas,
+    gif: Option<GifFile>,
+    terminal: Option<Terminal>
+}
+
+impl Animation {
+    // Start an animation the size of the canvas made by the canvas function, which is only
+    // called if there is an output. The delay is in hundredths of a second
+    pub fn start<F>(suffix: &str, canvas: F, palette: &[Rgb], delay: u16) -> Result<Option<Animation>>
+    where F: FnOnce() -> Result<Canvas> {
+        let options = options();
+        let gif_path = output_path(options.gif, suffix);
+
+        if gif_path.is_none() && !options.animate {
+            return Ok(None)
+        }
+
+        let canvas = canvas()?;
+
+        let gif = match gif_path {
+            Some(path) => Some(GifFile::create(&path, &canvas, palette, delay)?),
+            None => None
+        };
+
+        let terminal = if options.animate {
+            Some(Terminal::start(palette, options.fps.unwrap_or_else(|| 100 / delay.max(1) as u32)))
+        } else {
+            None
+        };
+
+        Ok(Some(Animation { canvas, gif, terminal }))
+    }
+
+    // Add a frame drawn on a cleared canvas
+    pub fn frame<F>(&mut self, draw: F)
+    where F: FnOnce(&mut dyn Draw) {
+        self.canvas.clear(0);
+        draw(&mut self.canvas);
+
+        if let Some(gif) = &mut self.gif {
+            gif.frame(&self.canvas);
+        }
+
+        if let Some(terminal) = &mut self.terminal {
+            terminal.frame(&self.canvas);
+        }
+    }
+
+    pub fn finish(self) -> Result<()> {
+        if let Some(terminal) = self.terminal {
+            terminal.finish()?;
+        }
+
+        if let Some(gif) = self.gif {
+            gif.finish()?;
+        }
+
+        Ok(())
+    }
+}
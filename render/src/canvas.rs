@@ -0,0 +1,132 @@
+use crate::Draw;
+use aoc::solution::Result;
+
+// An image made of palette colour indexes, optionally drawn in scaled up cells
+#[derive(Debug, Clone)]
+pub struct Canvas {
+    width: u16,
+    height: u16,
+    scale: u16,
+    pixels: Vec<u8>
+}
+
+impl Canvas {
+    pub fn new(width: u16, height: u16) -> Canvas {
+        Canvas {
+            width,
+            height,
+            scale: 1,
+            pixels: vec![0; width as usize * height as usize]
+        }
+    }
+
+    // A canvas for a grid of cols x rows cells, each scale pixels square
+    pub fn scaled(cols: u16, rows: u16, scale: u16) -> Result<Canvas> {
+        match (cols.checked_mul(scale), rows.checked_mul(scale)) {
+            (Some(width), Some(height)) => Ok(Canvas { scale, ..Canvas::new(width, height) }),
+            _ => Err(format!("Grid of {}x{} is too large to draw", cols, rows))?
+        }
+    }
+
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    // Pixels per cell
+    pub fn scale(&self) -> u16 {
+        self.scale
+    }
+
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+}
+
+impl Draw for Canvas {
+    fn clear(&mut self, colour: u8) {
+        self.pixels.fill(colour);
+    }
+
+    // Clipped to the canvas
+    fn rect(&mut self, x: u16, y: u16, w: u16, h: u16, colour: u8) {
+        let x_end = x.saturating_add(w).min(self.width) as usize;
+        let y_end = y.saturating_add(h).min(self.height);
+
+        for py in y..y_end {
+            let row = py as usize * self.width as usize;
+
+            if (x as usize) < x_end {
+                self.pixels[row + x as usize..row + x_end].fill(colour);
+            }
+        }
+    }
+
+    fn outline(&mut self, x: u16, y: u16, w: u16, h: u16, colour: u8) {
+        if w == 0 || h == 0 {
+            return
+        }
+
+        self.rect(x, y, w, 1, colour);
+        self.rect(x, y + h - 1, w, 1, colour);
+        self.rect(x, y, 1, h, colour);
+        self.rect(x + w - 1, y, 1, h, colour);
+    }
+
+    fn cell(&mut self, x: usize, y: usize, colour: u8) {
+        let scale = self.scale;
+
+        self.rect(x as u16 * scale, y as u16 * scale, scale, scale, colour);
+    }
+
+    // Straight runs are drawn as thick lines, anything else as whole cells
+    fn path(&mut self, cells: &[(usize, usize)], colour: u8) {
+        let scale = self.scale;
+        let thickness = (scale / 4).max(1);
+        let centre = |c: usize| (c as u16 * scale) + (scale - thickness) / 2;
+
+        for pair in cells.windows(2) {
+            let ((x1, y1), (x2, y2)) = (pair[0], pair[1]);
+
+            if x1 == x2 || y1 == y2 {
+                let (left, right) = (centre(x1.min(x2)), centre(x1.max(x2)));
+                let (top, bottom) = (centre(y1.min(y2)), centre(y1.max(y2)));
+
+                self.rect(left, top, right - left + thickness, bottom - top + thickness, colour);
+            } else {
+                self.cell(x1, y1, colour);
+                self.cell(x2, y2, colour);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_canvas() {
+    let mut canvas = Canvas::scaled(3, 2, 2).unwrap();
+
+    canvas.cell(1, 1, 1);
+    canvas.outline(0, 0, 3, 3, 2);
+
+    assert!(canvas.pixels() == [
+        2, 2, 2, 0, 0, 0,
+        2, 0, 2, 0, 0, 0,
+        2, 2, 2, 1, 0, 0,
+        0, 0, 1, 1, 0, 0,
+    ]);
+
+    canvas.clear(0);
+    canvas.path(&[(0, 0), (2, 0), (2, 1)], 3);
+
+    assert!(canvas.pixels() == [
+        3, 3, 3, 3, 3, 0,
+        0, 0, 0, 0, 3, 0,
+        0, 0, 0, 0, 3, 0,
+        0, 0, 0, 0, 0, 0,
+    ]);
+
+    assert!(Canvas::scaled(9000, 1, 8).unwrap_err().to_string() == "Grid of 9000x1 is too large to draw");
+}
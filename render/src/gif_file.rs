@@ -0,0 +1,56 @@
+use crate::{canvas::Canvas, Rgb};
+use aoc::solution::Result;
+use gif::{Encoder, EncodingError, Frame};
+use std::{borrow::Cow, fs::File, path::Path};
+
+// An animated GIF being written
+pub struct GifFile {
+    encoder: Encoder<File>,
+    width: u16,
+    height: u16,
+    delay: u16,
+    error: Option<EncodingError>
+}
+
+impl GifFile {
+    pub fn create(path: &Path, canvas: &Canvas, palette: &[Rgb], delay: u16) -> Result<GifFile> {
+        let file = File::create(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let encoder = Encoder::new(file, canvas.width(), canvas.height(), &palette.concat())?;
+
+        Ok(GifFile {
+            encoder,
+            width: canvas.width(),
+            height: canvas.height(),
+            delay,
+            error: None
+        })
+    }
+
+    // Add a frame. Errors are kept until finish
+    pub fn frame(&mut self, canvas: &Canvas) {
+        if self.error.is_some() {
+            return
+        }
+
+        assert!(canvas.width() == self.width && canvas.height() == self.height);
+
+        let frame = Frame {
+            delay: self.delay,
+            width: self.width,
+            height: self.height,
+            buffer: Cow::Borrowed(canvas.pixels()),
+            ..Frame::default()
+        };
+
+        if let Err(e) = self.encoder.write_frame(&frame) {
+            self.error = Some(e);
+        }
+    }
+
+    pub fn finish(self) -> Result<()> {
+        match self.error {
+            Some(e) => Err(e)?,
+            None => Ok(())
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use aoc::{input::Source, solution::{self, Result, Solution}};
+use std::{path::PathBuf, sync::Mutex};
+
+mod animation;
+mod canvas;
+mod gif_file;
+mod still;
+mod svg;
+mod terminal;
+
+pub use animation::Animation;
+pub use canvas::Canvas;
+pub use still::still;
+pub use svg::Svg;
+
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0x00, 0x00, 0x00];
+pub const WHITE: Rgb = [0xff, 0xff, 0xff];
+
+// Something to draw on, in pixels or in scaled up cells. Colours are palette indexes
+pub trait Draw {
+    fn clear(&mut self, colour: u8);
+
+    // Filled rectangle in pixels
+    fn rect(&mut self, x: u16, y: u16, w: u16, h: u16, colour: u8);
+
+    // Rectangle edges in pixels, w x h overall
+    fn outline(&mut self, x: u16, y: u16, w: u16, h: u16, colour: u8);
+
+    // Fill a cell
+    fn cell(&mut self, x: usize, y: usize, colour: u8);
+
+    // Line through the centres of a run of cells
+    fn path(&mut self, cells: &[(usize, usize)], colour: u8);
+}
+
+// Where pictures go. Nowhere by default
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    pub gif: Option<PathBuf>,
+    // Final pictures
+    pub png: Option<PathBuf>,
+    pub svg: Option<PathBuf>,
+    // Play in the terminal
+    pub animate: bool,
+    // Terminal frame rate, instead of each animation's own frame delay
+    pub fps: Option<u32>
+}
+
+impl Options {
+    // Command line options handled by parse_arg
+    pub const USAGE: &'static str = "[--gif PATH] [--png PATH] [--svg PATH] [--animate] [--fps N]";
+
+    // Handle a command line option, calling value for its argument. Returns false if it isn't a render option
+    pub fn parse_arg<F, S>(&mut self, arg: &str, value: F) -> Result<bool>
+    where F: FnOnce() -> std::result::Result<S, String>, S: AsRef<str> {
+        match arg {
+            "--gif" => self.gif = Some(PathBuf::from(value()?.as_ref())),
+            "--png" => self.png = Some(PathBuf::from(value()?.as_ref())),
+            "--svg" => self.svg = Some(PathBuf::from(value()?.as_ref())),
+            "--animate" => self.animate = true,
+            "--fps" => {
+                let value = value()?;
+
+                match value.as_ref().parse::<u32>() {
+                    Ok(fps) if fps > 0 => self.fps = Some(fps),
+                    _ => Err(format!("Invalid frame rate {}", value.as_ref()))?
+                }
+            }
+            _ => return Ok(false)
+        }
+
+        Ok(true)
+    }
+}
+
+static OPTIONS: Mutex<Options> = Mutex::new(Options { gif: None, png: None, svg: None, animate: false, fps: None });
+
+pub fn set_options(options: Options) {
+    *OPTIONS.lock().unwrap() = options;
+}
+
+fn options() -> Options {
+    OPTIONS.lock().unwrap().clone()
+}
+
+// An output path with a suffix added to the file name, for days with more than one picture
+fn output_path(path: Option<PathBuf>, suffix: &str) -> Option<PathBuf> {
+    let path = path?;
+
+    if suffix.is_empty() {
+        return Some(path)
+    }
+
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+
+    let name = match path.extension() {
+        Some(ext) => format!("{}-{}.{}", stem, suffix, ext.to_string_lossy()),
+        None => format!("{}-{}", stem, suffix)
+    };
+
+    Some(path.with_file_name(name))
+}
+
+// Body of the binary for a day which can draw: [INPUT] [render options] [config and logging options]
+pub fn main<S: Solution>(solution: S, default: fn() -> Source) -> Result<()> {
+    let mut options = Options::default();
+    let input = solution::parse_args(default, Options::USAGE, |arg, value| options.parse_arg(arg, value))?;
+
+    set_options(options);
+
+    solution::run(&solution, &input, None)
+}
+
+#[test]
+fn test_options() {
+    let mut options = Options::default();
+    let mut args = vec!["out/day13.gif", "x"].into_iter();
+
+    assert!(options.parse_arg("--gif", || Ok(args.next().unwrap())).unwrap());
+    assert!(options.parse_arg("--animate", || Err::<&str, _>("unused".to_string())).unwrap());
+    assert!(options.parse_arg("--fps", || Ok(args.next().unwrap())).unwrap_err().to_string() == "Invalid frame rate x");
+    assert!(!options.parse_arg("--part", || Ok("1")).unwrap());
+
+    assert!(output_path(None, "").is_none());
+    assert!(output_path(options.gif.clone(), "").unwrap() == std::path::Path::new("out/day13.gif"));
+    assert!(output_path(options.gif, "2").unwrap() == std::path::Path::new("out/day13-2.gif"));
+}
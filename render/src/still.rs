@@ -0,0 +1,46 @@
+use crate::{canvas::Canvas, options, output_path, svg::Svg, Draw, Rgb};
+use aoc::solution::Result;
+use std::{fs::{self, File}, io::BufWriter, path::Path};
+
+// Write a final picture to the PNG and SVG paths, if given. The canvas function is only called if there
+// is a path. Its canvas sets the size and is drawn on for the PNG
+pub fn still<C, F>(suffix: &str, canvas: C, palette: &[Rgb], mut draw: F) -> Result<()>
+where C: FnOnce() -> Result<Canvas>, F: FnMut(&mut dyn Draw) {
+    let options = options();
+    let (png_path, svg_path) = (output_path(options.png, suffix), output_path(options.svg, suffix));
+
+    if png_path.is_none() && svg_path.is_none() {
+        return Ok(())
+    }
+
+    let mut canvas = canvas()?;
+
+    if let Some(path) = png_path {
+        draw(&mut canvas);
+        write_png(&path, &canvas, palette).map_err(|e| format!("{}: {}", path.display(), e))?;
+    }
+
+    if let Some(path) = svg_path {
+        let mut svg = Svg::like(&canvas, palette);
+
+        draw(&mut svg);
+        fs::write(&path, svg.text()).map_err(|e| format!("{}: {}", path.display(), e))?;
+    }
+
+    Ok(())
+}
+
+fn write_png(path: &Path, canvas: &Canvas, palette: &[Rgb]) -> Result<()> {
+    let file = BufWriter::new(File::create(path)?);
+
+    let mut encoder = png::Encoder::new(file, canvas.width() as u32, canvas.height() as u32);
+    encoder.set_color(png::ColorType::Indexed);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_palette(palette.concat());
+
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(canvas.pixels())?;
+    writer.finish()?;
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use crate::{canvas::Canvas, Draw, Rgb, BLACK};
+use std::fmt::Write;
+
+// A vector picture with the same coordinates as a canvas
+pub struct Svg {
+    width: u16,
+    height: u16,
+    scale: u16,
+    palette: Vec<Rgb>,
+    elements: String
+}
+
+impl Svg {
+    pub fn like(canvas: &Canvas, palette: &[Rgb]) -> Svg {
+        let mut svg = Svg {
+            width: canvas.width(),
+            height: canvas.height(),
+            scale: canvas.scale(),
+            palette: palette.to_vec(),
+            elements: String::new()
+        };
+
+        svg.clear(0);
+
+        svg
+    }
+
+    pub fn text(&self) -> String {
+        format!("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{1}\" viewBox=\"0 0 {0} {1}\">\n{2}</svg>\n",
+            self.width, self.height, self.elements)
+    }
+
+    fn colour(&self, colour: u8) -> String {
+        let rgb = self.palette.get(colour as usize).unwrap_or(&BLACK);
+
+        format!("#{:02x}{:02x}{:02x}", rgb[0], rgb[1], rgb[2])
+    }
+}
+
+impl Draw for Svg {
+    fn clear(&mut self, colour: u8) {
+        self.elements.clear();
+        self.rect(0, 0, self.width, self.height, colour);
+    }
+
+    fn rect(&mut self, x: u16, y: u16, w: u16, h: u16, colour: u8) {
+        let fill = self.colour(colour);
+
+        writeln!(self.elements, "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>", x, y, w, h, fill).unwrap();
+    }
+
+    // A one pixel line just inside the edges, as on a canvas
+    fn outline(&mut self, x: u16, y: u16, w: u16, h: u16, colour: u8) {
+        if w == 0 || h == 0 {
+            return
+        }
+
+        let stroke = self.colour(colour);
+
+        writeln!(self.elements, "<rect x=\"{}.5\" y=\"{}.5\" width=\"{}\" height=\"{}\" fill=\"none\" stroke=\"{}\"/>",
+            x, y, w - 1, h - 1, stroke).unwrap();
+    }
+
+    fn cell(&mut self, x: usize, y: usize, colour: u8) {
+        let scale = self.scale;
+
+        self.rect(x as u16 * scale, y as u16 * scale, scale, scale, colour);
+    }
+
+    fn path(&mut self, cells: &[(usize, usize)], colour: u8) {
+        let scale = self.scale as f32;
+        let stroke = self.colour(colour);
+
+        let points = cells.iter().map(|&(x, y)| {
+            format!("{},{}", (x as f32 + 0.5) * scale, (y as f32 + 0.5) * scale)
+        }).collect::<Vec<_>>().join(" ");
+
+        writeln!(self.elements, "<polyline points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"{}\" stroke-linejoin=\"round\"/>",
+            points, stroke, (scale / 4.0).max(1.0)).unwrap();
+    }
+}
+
+#[test]
+fn test_svg() {
+    let mut svg = Svg::like(&Canvas::scaled(2, 1, 4).unwrap(), &[BLACK, [0xff, 0x80, 0x00]]);
+
+    svg.cell(1, 0, 1);
+    svg.path(&[(0, 0), (1, 0)], 1);
+
+    assert!(svg.text() == concat!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"8\" height=\"4\" viewBox=\"0 0 8 4\">\n",
+        "<rect x=\"0\" y=\"0\" width=\"8\" height=\"4\" fill=\"#000000\"/>\n",
+        "<rect x=\"4\" y=\"0\" width=\"4\" height=\"4\" fill=\"#ff8000\"/>\n",
+        "<polyline points=\"2,2 6,2\" fill=\"none\" stroke=\"#ff8000\" stroke-width=\"1\" stroke-linejoin=\"round\"/>\n",
+        "</svg>\n"
+    ));
+}
@@ -0,0 +1,138 @@
+use crate::{canvas::Canvas, Rgb, BLACK};
+#[cfg(test)]
+use crate::Draw;
+use aoc::solution::Result;
+use std::{fmt::Write as _, io::{self, Write}, thread, time::{Duration, Instant}};
+
+// Widest picture drawn, in characters. Wider canvases are sampled more sparsely
+const MAX_COLS: u16 = 160;
+
+// Animation played on stderr with ANSI colours, so stdout only has the answers
+pub struct Terminal {
+    palette: Vec<Rgb>,
+    frame_time: Duration,
+    last_frame: Option<Instant>,
+    error: Option<io::Error>
+}
+
+impl Terminal {
+    pub fn start(palette: &[Rgb], fps: u32) -> Terminal {
+        let mut terminal = Terminal {
+            palette: palette.to_vec(),
+            frame_time: Duration::from_secs(1) / fps.max(1),
+            last_frame: None,
+            error: None
+        };
+
+        // Clear the screen and hide the cursor
+        terminal.write("\x1b[2J\x1b[?25l");
+
+        terminal
+    }
+
+    pub fn frame(&mut self, canvas: &Canvas) {
+        if self.error.is_some() {
+            return
+        }
+
+        let text = ansi_frame(canvas, &self.palette, step(canvas));
+
+        // Keep to the frame rate
+        if let Some(last) = self.last_frame {
+            if let Some(wait) = self.frame_time.checked_sub(last.elapsed()) {
+                thread::sleep(wait);
+            }
+        }
+
+        self.last_frame = Some(Instant::now());
+
+        // Draw from the top left
+        self.write(&format!("\x1b[H{}", text));
+    }
+
+    pub fn finish(mut self) -> Result<()> {
+        // Show the cursor again
+        self.write("\x1b[0m\x1b[?25h");
+
+        match self.error {
+            Some(e) => Err(e)?,
+            None => Ok(())
+        }
+    }
+
+    // Errors are kept until finish
+    fn write(&mut self, text: &str) {
+        if self.error.is_some() {
+            return
+        }
+
+        let mut stderr = io::stderr();
+
+        if let Err(e) = stderr.write_all(text.as_bytes()).and_then(|_| stderr.flush()) {
+            self.error = Some(e);
+        }
+    }
+}
+
+// Pixels per character: at least a cell, more if the canvas is too wide
+fn step(canvas: &Canvas) -> u16 {
+    canvas.scale().max(canvas.width().div_ceil(MAX_COLS))
+}
+
+// The canvas as ANSI text, sampling every step pixels. Each character is two rows, using an upper half block
+fn ansi_frame(canvas: &Canvas, palette: &[Rgb], step: u16) -> String {
+    let width = canvas.width() as usize;
+    let cols = canvas.width().div_ceil(step) as usize;
+    let rows = canvas.height().div_ceil(step) as usize;
+    let step = step as usize;
+
+    let pixel = |x: usize, y: usize| {
+        let colour = canvas.pixels()[(y * step * width) + (x * step)];
+
+        *palette.get(colour as usize).unwrap_or(&BLACK)
+    };
+
+    let mut text = String::new();
+
+    for row in (0..rows).step_by(2) {
+        let mut last = None;
+
+        for col in 0..cols {
+            let top = pixel(col, row);
+            let bottom = if row + 1 < rows { Some(pixel(col, row + 1)) } else { None };
+
+            // Only change colour when needed
+            if last != Some((top, bottom)) {
+                write!(text, "\x1b[38;2;{};{};{}m", top[0], top[1], top[2]).unwrap();
+
+                match bottom {
+                    Some(b) => write!(text, "\x1b[48;2;{};{};{}m", b[0], b[1], b[2]).unwrap(),
+                    None => text.push_str("\x1b[49m")
+                }
+
+                last = Some((top, bottom));
+            }
+
+            text.push('▀');
+        }
+
+        text.push_str("\x1b[0m\n");
+    }
+
+    text
+}
+
+#[test]
+fn test_ansi_frame() {
+    let mut canvas = Canvas::scaled(2, 3, 2).unwrap();
+
+    canvas.cell(1, 0, 1);
+    canvas.cell(0, 2, 1);
+
+    let text = ansi_frame(&canvas, &[BLACK, [1, 2, 3]], step(&canvas));
+
+    assert!(text == concat!(
+        "\x1b[38;2;0;0;0m\x1b[48;2;0;0;0m▀\x1b[38;2;1;2;3m\x1b[48;2;0;0;0m▀\x1b[0m\n",
+        "\x1b[38;2;1;2;3m\x1b[49m▀\x1b[38;2;0;0;0m\x1b[49m▀\x1b[0m\n"
+    ));
+}
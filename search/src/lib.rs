@@ -0,0 +1,371 @@
+use std::{cmp::Reverse, collections::{BinaryHeap, HashMap, HashSet, VecDeque}, hash::Hash};
+
+pub type Cost = usize;
+
+// A problem to search: the states, the moves between them and how to spot repeated states
+pub trait Space {
+    type State: Clone;
+    type Key: Eq + Hash;
+
+    // States reachable in one move
+    fn neighbours(&self, state: &Self::State) -> Vec<Self::State>;
+
+    // Identifies equivalent states for the visited set
+    fn key(&self, state: &Self::State) -> Self::Key;
+
+    // Cost of a single move, used by Dijkstra and A*
+    fn cost(&self, _from: &Self::State, _to: &Self::State) -> Cost {
+        1
+    }
+
+    // Lower bound on the cost from a state to the goal, used by A*
+    fn estimate(&self, _state: &Self::State) -> Cost {
+        0
+    }
+}
+
+// How repeated states are handled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Visited {
+    // Each key is only expanded once, at its lowest cost
+    Once,
+    // Every path is followed. The state must stop the search growing forever
+    Never
+}
+
+// What to do after visiting a state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Flow {
+    Continue,
+    // Don't expand this state
+    Prune,
+    // End the search, returning the path to this state
+    Stop
+}
+
+impl Flow {
+    pub fn stop_if(stop: bool) -> Flow {
+        if stop {
+            Flow::Stop
+        } else {
+            Flow::Continue
+        }
+    }
+}
+
+// A path from the start state, including both ends
+#[derive(Debug, Clone)]
+pub struct Path<T> {
+    pub states: Vec<T>,
+    pub cost: Cost
+}
+
+struct Node<T> {
+    state: T,
+    parent: Option<usize>,
+    cost: Cost,
+    depth: usize
+}
+
+enum Frontier<'a> {
+    None,
+    Fifo(&'a VecDeque<usize>),
+    Heap(&'a BinaryHeap<(Reverse<Cost>, Reverse<usize>)>)
+}
+
+// A state being visited, with access to the search around it
+pub struct Visit<'a, T> {
+    pub state: &'a T,
+    pub cost: Cost,
+    pub depth: usize,
+    nodes: &'a [Node<T>],
+    idx: usize,
+    frontier: Frontier<'a>
+}
+
+impl<'a, T> Visit<'a, T> {
+    fn new(nodes: &'a [Node<T>], idx: usize, frontier: Frontier<'a>) -> Visit<'a, T> {
+        let node = &nodes[idx];
+
+        Visit {
+            state: &node.state,
+            cost: node.cost,
+            depth: node.depth,
+            nodes,
+            idx,
+            frontier
+        }
+    }
+
+    // States from the start to this one
+    pub fn path(&self) -> Vec<&'a T> {
+        let mut path = Vec::with_capacity(self.depth + 1);
+        let mut idx = Some(self.idx);
+
+        while let Some(i) = idx {
+            path.push(&self.nodes[i].state);
+            idx = self.nodes[i].parent;
+        }
+
+        path.reverse();
+        path
+    }
+
+    // Every state reached so far. For DFS this is just the current path
+    pub fn discovered(&self) -> impl Iterator<Item = &'a T> {
+        self.nodes.iter().map(|n| &n.state)
+    }
+
+    // States waiting to be visited
+    pub fn frontier(&self) -> Vec<&'a T> {
+        match self.frontier {
+            Frontier::None => Vec::new(),
+            Frontier::Fifo(queue) => queue.iter().map(|&i| &self.nodes[i].state).collect(),
+            Frontier::Heap(heap) => heap.iter().map(|(_, Reverse(i))| &self.nodes[*i].state).collect()
+        }
+    }
+}
+
+fn build_path<T: Clone>(nodes: &[Node<T>], idx: usize) -> Path<T> {
+    let states = Visit::new(nodes, idx, Frontier::None).path().into_iter().cloned().collect();
+
+    Path { states, cost: nodes[idx].cost }
+}
+
+// Breadth first search. Every move counts as one
+pub fn bfs<S, F>(space: &S, start: S::State, visited: Visited, mut visit: F) -> Option<Path<S::State>>
+where S: Space, F: FnMut(&Visit<S::State>) -> Flow {
+    let mut seen = HashSet::new();
+    let mut nodes = Vec::new();
+    let mut queue = VecDeque::new();
+
+    if visited == Visited::Once {
+        seen.insert(space.key(&start));
+    }
+
+    nodes.push(Node { state: start, parent: None, cost: 0, depth: 0 });
+    queue.push_back(0);
+
+    while let Some(idx) = queue.pop_front() {
+        match visit(&Visit::new(&nodes, idx, Frontier::Fifo(&queue))) {
+            Flow::Continue => {}
+            Flow::Prune => continue,
+            Flow::Stop => return Some(build_path(&nodes, idx))
+        }
+
+        for next in space.neighbours(&nodes[idx].state) {
+            if visited == Visited::Once && !seen.insert(space.key(&next)) {
+                continue
+            }
+
+            let depth = nodes[idx].depth + 1;
+
+            queue.push_back(nodes.len());
+            nodes.push(Node { state: next, parent: Some(idx), cost: depth, depth });
+        }
+    }
+
+    None
+}
+
+// Lowest cost first search using the move costs
+pub fn dijkstra<S, F>(space: &S, start: S::State, visited: Visited, visit: F) -> Option<Path<S::State>>
+where S: Space, F: FnMut(&Visit<S::State>) -> Flow {
+    best_first(space, start, visited, false, visit)
+}
+
+// Lowest cost plus estimate first. The estimate must never exceed the real remaining cost
+pub fn astar<S, F>(space: &S, start: S::State, visited: Visited, visit: F) -> Option<Path<S::State>>
+where S: Space, F: FnMut(&Visit<S::State>) -> Flow {
+    best_first(space, start, visited, true, visit)
+}
+
+fn best_first<S, F>(space: &S, start: S::State, visited: Visited, use_estimate: bool, mut visit: F) -> Option<Path<S::State>>
+where S: Space, F: FnMut(&Visit<S::State>) -> Flow {
+    let priority = |state: &S::State, cost: Cost| {
+        if use_estimate {
+            cost + space.estimate(state)
+        } else {
+            cost
+        }
+    };
+
+    // Lowest cost found for each key
+    let mut best: HashMap<S::Key, Cost> = HashMap::new();
+    let mut nodes = Vec::new();
+    let mut heap = BinaryHeap::new();
+
+    if visited == Visited::Once {
+        best.insert(space.key(&start), 0);
+    }
+
+    heap.push((Reverse(priority(&start, 0)), Reverse(0)));
+    nodes.push(Node { state: start, parent: None, cost: 0, depth: 0 });
+
+    while let Some((_, Reverse(idx))) = heap.pop() {
+        // Skip if a cheaper way here has been found since this was queued
+        if visited == Visited::Once && best.get(&space.key(&nodes[idx].state)).is_some_and(|&c| c < nodes[idx].cost) {
+            continue
+        }
+
+        match visit(&Visit::new(&nodes, idx, Frontier::Heap(&heap))) {
+            Flow::Continue => {}
+            Flow::Prune => continue,
+            Flow::Stop => return Some(build_path(&nodes, idx))
+        }
+
+        for next in space.neighbours(&nodes[idx].state) {
+            let cost = nodes[idx].cost + space.cost(&nodes[idx].state, &next);
+
+            if visited == Visited::Once {
+                let key = space.key(&next);
+
+                match best.get(&key) {
+                    Some(&c) if c <= cost => continue,
+                    _ => best.insert(key, cost)
+                };
+            }
+
+            heap.push((Reverse(priority(&next, cost)), Reverse(nodes.len())));
+            nodes.push(Node { state: next, parent: Some(idx), cost, depth: nodes[idx].depth + 1 });
+        }
+    }
+
+    None
+}
+
+// Depth first search. With Visited::Never every path is visited, which suits finding the longest
+pub fn dfs<S, F>(space: &S, start: S::State, visited: Visited, mut visit: F) -> Option<Path<S::State>>
+where S: Space, F: FnMut(&Visit<S::State>) -> Flow {
+    let mut seen = HashSet::new();
+
+    if visited == Visited::Once {
+        seen.insert(space.key(&start));
+    }
+
+    // The nodes are the current path
+    let mut nodes = vec![Node { state: start, parent: None, cost: 0, depth: 0 }];
+
+    if dfs_iter(space, &mut nodes, visited, &mut seen, &mut visit) {
+        Some(build_path(&nodes, nodes.len() - 1))
+    } else {
+        None
+    }
+}
+
+// Returns true if the search was stopped
+fn dfs_iter<S, F>(space: &S, nodes: &mut Vec<Node<S::State>>, visited: Visited, seen: &mut HashSet<S::Key>, visit: &mut F) -> bool
+where S: Space, F: FnMut(&Visit<S::State>) -> Flow {
+    let idx = nodes.len() - 1;
+
+    match visit(&Visit::new(nodes, idx, Frontier::None)) {
+        Flow::Continue => {}
+        Flow::Prune => return false,
+        Flow::Stop => return true
+    }
+
+    for next in space.neighbours(&nodes[idx].state) {
+        if visited == Visited::Once && !seen.insert(space.key(&next)) {
+            continue
+        }
+
+        let cost = nodes[idx].cost + space.cost(&nodes[idx].state, &next);
+
+        nodes.push(Node { state: next, parent: Some(idx), cost, depth: idx + 1 });
+
+        if dfs_iter(space, nodes, visited, seen, visit) {
+            return true
+        }
+
+        nodes.pop();
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Weighted graph given as (from, to, cost) edges
+    struct Graph(Vec<(u8, u8, Cost)>);
+
+    impl Space for Graph {
+        type State = u8;
+        type Key = u8;
+
+        fn neighbours(&self, state: &u8) -> Vec<u8> {
+            self.0.iter().filter(|(f, _, _)| f == state).map(|(_, t, _)| *t).collect()
+        }
+
+        fn key(&self, state: &u8) -> u8 {
+            *state
+        }
+
+        fn cost(&self, from: &u8, to: &u8) -> Cost {
+            self.0.iter().find(|(f, t, _)| f == from && t == to).unwrap().2
+        }
+
+        // Lower bounds on the cost to reach 4
+        fn estimate(&self, state: &u8) -> Cost {
+            [5, 9, 3, 2, 0][*state as usize]
+        }
+    }
+
+    fn graph() -> Graph {
+        Graph(vec![(0, 1, 1), (1, 4, 10), (0, 2, 2), (2, 3, 2), (3, 4, 2), (4, 0, 1)])
+    }
+
+    #[test]
+    fn test_bfs() {
+        let path = bfs(&graph(), 0, Visited::Once, |v| Flow::stop_if(*v.state == 4)).unwrap();
+
+        assert!(path.states == vec![0, 1, 4]);
+        assert!(path.cost == 2);
+
+        let mut count = 0;
+        assert!(bfs(&graph(), 0, Visited::Once, |_| { count += 1; Flow::Continue }).is_none());
+        assert!(count == 5);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let path = dijkstra(&graph(), 0, Visited::Once, |v| Flow::stop_if(*v.state == 4)).unwrap();
+
+        assert!(path.states == vec![0, 2, 3, 4]);
+        assert!(path.cost == 6);
+    }
+
+    #[test]
+    fn test_astar() {
+        let mut visited = Vec::new();
+
+        let path = astar(&graph(), 0, Visited::Once, |v| {
+            visited.push(*v.state);
+            Flow::stop_if(*v.state == 4)
+        }).unwrap();
+
+        assert!(path.states == vec![0, 2, 3, 4]);
+        assert!(path.cost == 6);
+
+        // The estimate for 1 puts it behind the goal, so it's never expanded
+        assert!(visited == vec![0, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_dfs() {
+        // Without a visited set every path to 4 is found, cutting the cycle back to 0
+        let mut costs = Vec::new();
+
+        dfs(&graph(), 0, Visited::Never, |v| {
+            if *v.state == 4 {
+                costs.push((v.cost, v.path().len()));
+                Flow::Prune
+            } else {
+                Flow::Continue
+            }
+        });
+
+        assert!(costs == vec![(11, 3), (6, 4)]);
+    }
+}